@@ -40,13 +40,66 @@ impl Colour {
 }
 
 #[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum EdgePaddingSetting {
+    Uniform(i32),
+    PerSide(EdgePadding),
+}
+
+impl EdgePaddingSetting {
+    fn to_edge_padding(&self) -> EdgePadding {
+        match self {
+            EdgePaddingSetting::Uniform(val) => EdgePadding::uniform(*val),
+
+            EdgePaddingSetting::PerSide(val) => val.clone(),
+        }
+    }
+}
+
+#[derive(Default, Deserialize, Serialize)]
+enum PresetLayouts {
+    #[default]
+    Seed,
+    InMemory,
+    Disabled,
+}
+
+#[derive(Deserialize, Serialize)]
+struct DerivedLayoutSetting {
+    name: std::path::PathBuf,
+    base: std::path::PathBuf,
+    #[serde(default)]
+    mirror: Option<Axis>,
+    #[serde(default)]
+    quarter_turns: i32,
+}
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(default)]
+struct MonitorUserSettings {
+    edge_padding: Option<EdgePaddingSetting>,
+    struts: Option<EdgePadding>,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
 pub struct UserSettings {
     default_layout: std::path::PathBuf,
     window_padding: i32,
-    edge_padding: i32,
+    edge_padding: EdgePaddingSetting,
+    struts: EdgePadding,
+    monitors: std::collections::HashMap<String, MonitorUserSettings>,
     disable_rounding: bool,
     disable_unfocused_border: bool,
     focused_border_colour: Colour,
+    frame_insets: FrameInsets,
+    frame_insets_overrides: std::collections::HashMap<String, FrameInsets>,
+    use_dwm_frame_insets: bool,
+    smart_gaps: bool,
+    smart_gaps_hide_border: bool,
+    rewrite_migrated_layouts: bool,
+    preset_layouts: PresetLayouts,
+    derived_layouts: Vec<DerivedLayoutSetting>,
 }
 
 impl Default for UserSettings {
@@ -54,7 +107,9 @@ impl Default for UserSettings {
         UserSettings {
             default_layout: std::path::PathBuf::new(),
             window_padding: 0,
-            edge_padding: 0,
+            edge_padding: EdgePaddingSetting::Uniform(0),
+            struts: EdgePadding::default(),
+            monitors: std::collections::HashMap::new(),
             disable_rounding: false,
             disable_unfocused_border: false,
             focused_border_colour: Colour {
@@ -62,6 +117,14 @@ impl Default for UserSettings {
                 g: 255,
                 b: 255,
             },
+            frame_insets: FrameInsets::default(),
+            frame_insets_overrides: std::collections::HashMap::new(),
+            use_dwm_frame_insets: false,
+            smart_gaps: false,
+            smart_gaps_hide_border: false,
+            rewrite_migrated_layouts: false,
+            preset_layouts: PresetLayouts::default(),
+            derived_layouts: Vec::new(),
         }
     }
 }
@@ -69,32 +132,66 @@ impl Default for UserSettings {
 impl UserSettings {
     pub fn to_settings(
         &self,
-        layouts: &Vec<(std::path::PathBuf, himewm_layout::Layout)>,
+        layouts: &[(std::path::PathBuf, himewm_layout::Layout)],
     ) -> himewm::Settings {
+        let mut default_layout_idx = 0;
+
         if self.default_layout != std::path::Path::new("") {
             for (idx, (p, _)) in layouts.iter().enumerate() {
                 if p == &self.default_layout {
-                    return himewm::Settings {
-                        default_layout_idx: idx,
-                        window_padding: self.window_padding,
-                        edge_padding: self.edge_padding,
-                        disable_rounding: self.disable_rounding,
-                        disable_unfocused_border: self.disable_unfocused_border,
-                        focused_border_colour: self.focused_border_colour.as_colorref(),
-                    };
+                    default_layout_idx = idx;
+
+                    break;
                 }
             }
         }
 
         return himewm::Settings {
-            default_layout_idx: 0,
+            default_layout_idx,
             window_padding: self.window_padding,
-            edge_padding: self.edge_padding,
+            edge_padding: self.edge_padding.to_edge_padding(),
+            struts: self.struts.clone(),
+            monitor_overrides: self.get_monitor_overrides(),
             disable_rounding: self.disable_rounding,
             disable_unfocused_border: self.disable_unfocused_border,
             focused_border_colour: self.focused_border_colour.as_colorref(),
+            frame_insets: self.frame_insets.clone(),
+            frame_insets_overrides: self.get_frame_insets_overrides(),
+            use_dwm_frame_insets: self.use_dwm_frame_insets,
+            smart_gaps: self.smart_gaps,
+            smart_gaps_hide_border: self.smart_gaps_hide_border,
         };
     }
+
+    fn get_monitor_overrides(&self) -> std::collections::HashMap<String, himewm::MonitorSettings> {
+        self.monitors
+            .iter()
+            .map(|(name, monitor)| {
+                (
+                    name.clone(),
+                    himewm::MonitorSettings {
+                        edge_padding: match &monitor.edge_padding {
+                            Some(val) => val.to_edge_padding(),
+
+                            None => self.edge_padding.to_edge_padding(),
+                        },
+                        struts: match &monitor.struts {
+                            Some(val) => val.clone(),
+
+                            None => self.struts.clone(),
+                        },
+                    },
+                )
+            })
+            .collect()
+    }
+
+    fn get_frame_insets_overrides(&self) -> std::collections::HashMap<String, FrameInsets> {
+        self.frame_insets_overrides
+            .iter()
+            .map(|(name, frame_insets)| (name.to_lowercase(), frame_insets.clone()))
+            .collect()
+    }
 }
 
 pub fn create_dirs() -> std::io::Result<()> {
@@ -134,23 +231,109 @@ pub fn initialize_settings() -> UserSettings {
     }
 }
 
-pub fn initialize_layouts() -> Option<Vec<(std::path::PathBuf, Layout)>> {
+fn default_monitor_rect() -> Zone {
+    Zone {
+        left: 0,
+        top: 0,
+        right: 1920,
+        bottom: 1080,
+    }
+}
+
+pub fn initialize_layouts(
+    user_settings: &UserSettings,
+) -> Option<Vec<(std::path::PathBuf, Layout)>> {
     let mut ret = Vec::new();
 
+    let mut rejected = Vec::new();
+
     let dirs = Directories::new();
 
-    for entry_result in std::fs::read_dir(dirs.layouts_dir).unwrap() {
+    let layouts_dir_is_empty = std::fs::read_dir(&dirs.layouts_dir)
+        .unwrap()
+        .next()
+        .is_none();
+
+    for entry_result in std::fs::read_dir(&dirs.layouts_dir).unwrap() {
         match entry_result {
             Ok(entry) => match std::fs::read(entry.path()) {
                 Ok(byte_vector) => {
-                    let layout: Layout = match serde_json::from_slice(byte_vector.as_slice()) {
-                        Ok(val) => val,
-
-                        Err(_) => continue,
+                    let (mut layout, migrated) = match entry.path().extension() {
+                        Some(val) if val == "layout" => {
+                            match std::str::from_utf8(byte_vector.as_slice())
+                                .map(|src| Layout::parse_dsl(src, &default_monitor_rect()))
+                            {
+                                Ok(Ok(val)) => (val, false),
+
+                                Ok(Err(error)) => {
+                                    rejected.push(format!(
+                                        "{}:{}:{}: {}",
+                                        entry.path().display(),
+                                        error.line,
+                                        error.column,
+                                        error.message
+                                    ));
+
+                                    continue;
+                                }
+
+                                Err(error) => {
+                                    rejected.push(format!(
+                                        "{}:\n  {}",
+                                        entry.path().display(),
+                                        error
+                                    ));
+
+                                    continue;
+                                }
+                            }
+                        }
+
+                        Some(val) if val == "relative" => {
+                            match RelativeLayout::from_json(byte_vector.as_slice()) {
+                                Ok(val) => (val.to_layout(&default_monitor_rect()), false),
+
+                                Err(_) => continue,
+                            }
+                        }
+
+                        _ => match Layout::from_json(byte_vector.as_slice()) {
+                            Ok(val) => val,
+
+                            Err(error) => {
+                                rejected.push(format!("{}:\n  {}", entry.path().display(), error));
+
+                                continue;
+                            }
+                        },
                     };
 
+                    if let Err(errors) = layout.validate() {
+                        rejected.push(format!(
+                            "{}:\n{}",
+                            entry.path().display(),
+                            errors
+                                .iter()
+                                .map(|error| format!("  {}", error))
+                                .collect::<Vec<String>>()
+                                .join("\n")
+                        ));
+
+                        continue;
+                    }
+
+                    if migrated && user_settings.rewrite_migrated_layouts {
+                        if let Ok(val) = layout.to_json() {
+                            let _write_layout = std::fs::write(entry.path(), val);
+                        }
+                    }
+
                     let layout_name = std::path::Path::new(&entry.file_name()).with_extension("");
 
+                    if layout.get_name().is_empty() {
+                        layout.set_name(&layout_name.to_string_lossy());
+                    }
+
                     ret.push((layout_name, layout));
                 }
 
@@ -161,6 +344,64 @@ pub fn initialize_layouts() -> Option<Vec<(std::path::PathBuf, Layout)>> {
         }
     }
 
+    if !rejected.is_empty() {
+        himewm::show_warning_message(&format!(
+            "Skipped invalid layouts:\n{}",
+            rejected.join("\n")
+        ));
+    }
+
+    if ret.is_empty() {
+        for preset in Preset::ALL {
+            let layout = preset.layout(&default_monitor_rect());
+
+            match user_settings.preset_layouts {
+                PresetLayouts::Seed if layouts_dir_is_empty => {
+                    if let Ok(val) = layout.to_json() {
+                        if let Ok(mut layout_file) = std::fs::OpenOptions::new()
+                            .write(true)
+                            .create_new(true)
+                            .open(dirs.layouts_dir.join(preset.name()).with_extension("json"))
+                        {
+                            let _write_layout = std::io::Write::write_all(&mut layout_file, &val);
+                        }
+                    }
+                }
+
+                PresetLayouts::Seed => (),
+
+                PresetLayouts::InMemory => (),
+
+                PresetLayouts::Disabled => return None,
+            }
+
+            ret.push((std::path::PathBuf::from(preset.name()), layout));
+        }
+    }
+
+    for derived in user_settings.derived_layouts.iter() {
+        if ret.iter().any(|(p, _)| p == &derived.name) {
+            continue;
+        }
+
+        let base = match ret.iter().find(|(p, _)| p == &derived.base) {
+            Some((_, val)) => val,
+
+            None => continue,
+        };
+
+        let mut layout = match derived.mirror {
+            Some(axis) => base.mirrored(axis),
+
+            None => base.clone(),
+        }
+        .rotated(derived.quarter_turns);
+
+        layout.set_name(&derived.name.to_string_lossy());
+
+        ret.push((derived.name.clone(), layout));
+    }
+
     if ret.is_empty() {
         return None;
     } else {
@@ -5,7 +5,7 @@ use windows::{
     Win32::{
         Foundation::*,
         Graphics::{Dwm::*, Gdi::*},
-        System::{Com::*, Console::*},
+        System::{Com::*, Console::*, Threading::*},
         UI::{
             Accessibility::*, HiDpi::*, Input::KeyboardAndMouse::*, Shell::*,
             WindowsAndMessaging::*,
@@ -65,6 +65,16 @@ mod hotkey_identifiers {
     pub const TOGGLE_WINDOW: usize = 14;
 
     pub const TOGGLE_WORKSPACE: usize = 15;
+
+    pub const SHRINK_MASTER: usize = 16;
+
+    pub const GROW_MASTER: usize = 17;
+
+    pub const REMOVE_MASTER: usize = 18;
+
+    pub const ADD_MASTER: usize = 19;
+
+    pub const CYCLE_STACK: usize = 20;
 }
 
 enum CycleDirection {
@@ -74,13 +84,26 @@ enum CycleDirection {
 
 const CREATE_RETRIES: i32 = 1000;
 
+#[derive(Clone)]
+pub struct MonitorSettings {
+    pub edge_padding: EdgePadding,
+    pub struts: EdgePadding,
+}
+
 pub struct Settings {
     pub default_layout_idx: usize,
     pub window_padding: i32,
-    pub edge_padding: i32,
+    pub edge_padding: EdgePadding,
+    pub struts: EdgePadding,
+    pub monitor_overrides: std::collections::HashMap<String, MonitorSettings>,
     pub disable_rounding: bool,
     pub disable_unfocused_border: bool,
     pub focused_border_colour: COLORREF,
+    pub frame_insets: FrameInsets,
+    pub frame_insets_overrides: std::collections::HashMap<String, FrameInsets>,
+    pub use_dwm_frame_insets: bool,
+    pub smart_gaps: bool,
+    pub smart_gaps_hide_border: bool,
 }
 
 impl Default for Settings {
@@ -88,10 +111,17 @@ impl Default for Settings {
         Settings {
             default_layout_idx: 0,
             window_padding: 0,
-            edge_padding: 0,
+            edge_padding: EdgePadding::default(),
+            struts: EdgePadding::default(),
+            monitor_overrides: std::collections::HashMap::new(),
             disable_rounding: false,
             disable_unfocused_border: false,
             focused_border_colour: COLORREF(0x00FFFFFF),
+            frame_insets: FrameInsets::default(),
+            frame_insets_overrides: std::collections::HashMap::new(),
+            use_dwm_frame_insets: false,
+            smart_gaps: false,
+            smart_gaps_hide_border: false,
         }
     }
 }
@@ -104,6 +134,35 @@ impl Settings {
             return COLORREF(DWMWA_COLOR_DEFAULT);
         }
     }
+
+    unsafe fn get_monitor_settings(&self, hmonitor: HMONITOR) -> MonitorSettings {
+        if let Some(val) =
+            get_monitor_name(hmonitor).and_then(|name| self.monitor_overrides.get(&name))
+        {
+            return val.clone();
+        }
+
+        return MonitorSettings {
+            edge_padding: self.edge_padding.clone(),
+            struts: self.struts.clone(),
+        };
+    }
+
+    unsafe fn get_frame_insets(&self, hwnd: HWND) -> FrameInsets {
+        if let Some(val) =
+            get_process_name(hwnd).and_then(|name| self.frame_insets_overrides.get(&name))
+        {
+            return val.clone();
+        }
+
+        if self.use_dwm_frame_insets {
+            if let Some(val) = get_dwm_frame_insets(hwnd) {
+                return val;
+            }
+        }
+
+        return self.frame_insets.clone();
+    }
 }
 
 #[derive(Clone)]
@@ -149,10 +208,13 @@ pub struct WindowManager {
     window_info: std::collections::HashMap<*mut core::ffi::c_void, WindowInfo>,
     workspaces: std::collections::HashMap<(GUID, *mut core::ffi::c_void), Workspace>,
     layouts: std::collections::HashMap<*mut core::ffi::c_void, Vec<Layout>>,
+    monitor_settings: std::collections::HashMap<*mut core::ffi::c_void, MonitorSettings>,
     foreground_window: Option<HWND>,
     grabbed_window: Option<HWND>,
     ignored_combinations: std::collections::HashSet<(GUID, *mut core::ffi::c_void)>,
     ignored_windows: std::collections::HashSet<*mut core::ffi::c_void>,
+    managed_since: std::collections::HashMap<*mut core::ffi::c_void, std::time::Instant>,
+    overflow_windows: Vec<*mut core::ffi::c_void>,
     settings: Settings,
 }
 
@@ -180,10 +242,13 @@ impl WindowManager {
             window_info: std::collections::HashMap::new(),
             workspaces: std::collections::HashMap::new(),
             layouts: std::collections::HashMap::new(),
+            monitor_settings: std::collections::HashMap::new(),
             foreground_window: None,
             grabbed_window: None,
             ignored_combinations: std::collections::HashSet::new(),
             ignored_windows: std::collections::HashSet::new(),
+            managed_since: std::collections::HashMap::new(),
+            overflow_windows: Vec::new(),
             settings,
         }
     }
@@ -197,19 +262,27 @@ impl WindowManager {
         );
 
         for layout in layouts {
-            let monitor_rect = layout.get_monitor_rect();
-
             for (hmonitor, layouts) in self.layouts.iter_mut() {
-                let mut layout = match convert_for_monitor(&layout, HMONITOR(*hmonitor)) {
+                let monitor_settings = self.monitor_settings.get(hmonitor).unwrap();
+
+                let mut layout = match convert_for_monitor(
+                    &layout,
+                    HMONITOR(*hmonitor),
+                    &monitor_settings.struts,
+                ) {
                     Some(val) => val,
 
                     None => layout.clone(),
                 };
 
+                let monitor_rect = layout.get_monitor_rect().to_owned();
+
                 layout.update_all(
                     self.settings.window_padding,
-                    self.settings.edge_padding,
-                    monitor_rect,
+                    &monitor_settings.edge_padding,
+                    &self.settings.frame_insets,
+                    self.settings.smart_gaps,
+                    &monitor_rect,
                 );
 
                 layouts.push(layout);
@@ -307,6 +380,8 @@ impl WindowManager {
                     WindowInfo::new(desktop_id, monitor_handle, is_restored(hwnd), 0),
                 );
 
+                self.managed_since.insert(hwnd.0, std::time::Instant::now());
+
                 self.push_hwnd(desktop_id, monitor_handle, hwnd);
 
                 self.initialize_border(hwnd);
@@ -338,6 +413,10 @@ impl WindowManager {
 
         self.window_info.remove(&hwnd.0);
 
+        self.managed_since.remove(&hwnd.0);
+
+        self.overflow_windows.retain(|h| *h != hwnd.0);
+
         if self.foreground_window == Some(hwnd) {
             self.foreground_window = None;
         }
@@ -543,6 +622,8 @@ impl WindowManager {
 
         GetWindowRect(hwnd, &mut moved_to).unwrap();
 
+        let frame_insets = self.settings.get_frame_insets(hwnd);
+
         let moved_to_area = (moved_to.right - moved_to.left) * (moved_to.bottom - moved_to.top);
 
         let workspace;
@@ -607,7 +688,13 @@ impl WindowManager {
 
                     variant.update(
                         self.settings.window_padding,
-                        self.settings.edge_padding,
+                        &self
+                            .monitor_settings
+                            .get(&new_monitor_handle.0)
+                            .unwrap()
+                            .edge_padding,
+                        &self.settings.frame_insets,
+                        self.settings.smart_gaps,
                         &monitor_rect,
                     );
                 }
@@ -620,7 +707,8 @@ impl WindowManager {
             };
 
             if !changed_monitors {
-                let position = &positions[idx];
+                let position =
+                    &positions[idx].with_frame_insets(&self.settings.frame_insets, &frame_insets);
 
                 if moved_to.left == position.x
                     && moved_to.top == position.y
@@ -720,6 +808,78 @@ impl WindowManager {
         let _ = SetForegroundWindow(workspace.managed_window_handles[to]);
     }
 
+    unsafe fn cycle_stack(&self) {
+        let foreground_window = match self.foreground_window {
+            Some(hwnd) if !self.ignored_windows.contains(&hwnd.0) => hwnd,
+
+            _ => return,
+        };
+
+        let WindowInfo {
+            desktop_id,
+            monitor_handle,
+            restored: _,
+            idx,
+        } = match self.window_info.get(&foreground_window.0) {
+            Some(val) if val.restored => val.to_owned(),
+
+            _ => return,
+        };
+
+        let workspace = match self.workspaces.get(&(desktop_id, monitor_handle.0)) {
+            Some(val) if val.managed_window_handles.len() > 1 => val,
+
+            _ => return,
+        };
+
+        let variant = &self.layouts.get(&monitor_handle.0).unwrap()[workspace.layout_idx]
+            .get_variants()[workspace.variant_idx];
+
+        if variant.positions_len() < workspace.managed_window_handles.len() {
+            return;
+        }
+
+        let positions = variant.get_positions_at(workspace.managed_window_handles.len() - 1);
+
+        let position = match positions.get(idx) {
+            Some(val) => val,
+
+            None => return,
+        };
+
+        let stack: Vec<usize> = (0..positions.len())
+            .filter(|i| positions[*i] == *position)
+            .collect();
+
+        if stack.len() < 2 {
+            return;
+        }
+
+        let stack_idx = stack.iter().position(|i| *i == idx).unwrap();
+
+        let to = stack[(stack_idx + 1) % stack.len()];
+
+        let top = workspace.managed_window_handles[to];
+
+        let flags = SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE;
+
+        let _ = SetWindowPos(top, Some(HWND_TOP), 0, 0, 0, 0, flags);
+
+        for i in stack.iter().filter(|i| **i != to) {
+            let _ = SetWindowPos(
+                workspace.managed_window_handles[*i],
+                Some(top),
+                0,
+                0,
+                0,
+                0,
+                flags,
+            );
+        }
+
+        let _ = SetForegroundWindow(top);
+    }
+
     unsafe fn cycle_swap(&mut self, direction: CycleDirection) {
         let foreground_window = match self.foreground_window {
             Some(hwnd) => hwnd,
@@ -897,6 +1057,21 @@ impl WindowManager {
         self.update_workspace(desktop_id, monitor_handle);
     }
 
+    pub fn get_active_label(&self) -> Option<String> {
+        let window_info = self.window_info.get(&self.foreground_window?.0)?;
+
+        let workspace = self
+            .workspaces
+            .get(&(window_info.desktop_id, window_info.monitor_handle.0))?;
+
+        let layout = self
+            .layouts
+            .get(&window_info.monitor_handle.0)?
+            .get(workspace.layout_idx)?;
+
+        return Some(layout.variant_label(workspace.variant_idx));
+    }
+
     unsafe fn cycle_focused_monitor(&self, direction: CycleDirection) {
         if self.monitor_handles.len() <= 1 {
             return;
@@ -1106,15 +1281,7 @@ impl WindowManager {
             let position = &layout.get_positions_at(workspace.managed_window_handles.len() - 1)
                 [workspace.managed_window_handles.len() - 1];
 
-            let _ = SetWindowPos(
-                foreground_window,
-                None,
-                position.x,
-                position.y,
-                position.cx,
-                position.cy,
-                SWP_NOZORDER,
-            );
+            let _ = set_window_position(foreground_window, position, &self.settings);
         }
     }
 
@@ -1248,15 +1415,7 @@ impl WindowManager {
                 let position =
                     &layout.get_positions_at(workspace.managed_window_handles.len() - 1)[new_idx];
 
-                let _ = SetWindowPos(
-                    grabbed_window,
-                    None,
-                    position.x,
-                    position.y,
-                    position.cx,
-                    position.cy,
-                    SWP_NOZORDER,
-                );
+                let _ = set_window_position(grabbed_window, position, &self.settings);
             }
         }
 
@@ -1284,6 +1443,8 @@ impl WindowManager {
         };
 
         if self.ignored_windows.remove(&foreground_window.0) {
+            self.overflow_windows.retain(|h| *h != foreground_window.0);
+
             if restored {
                 let original_dpi = GetDpiForWindow(foreground_window);
 
@@ -1305,15 +1466,7 @@ impl WindowManager {
                         .get_positions_at(workspace.managed_window_handles.len() - 1)
                         [workspace.managed_window_handles.len() - 1];
 
-                    let _ = SetWindowPos(
-                        foreground_window,
-                        None,
-                        position.x,
-                        position.y,
-                        position.cx,
-                        position.cy,
-                        SWP_NOZORDER,
-                    );
+                    let _ = set_window_position(foreground_window, position, &self.settings);
                 }
             }
         } else {
@@ -1353,11 +1506,186 @@ impl WindowManager {
         }
     }
 
+    unsafe fn adjust_master(&mut self, adjust: impl Fn(&mut Variant)) {
+        let foreground_window = match self.foreground_window {
+            Some(hwnd) => hwnd,
+
+            None => return,
+        };
+
+        let WindowInfo {
+            desktop_id,
+            monitor_handle,
+            ..
+        } = match self.window_info.get(&foreground_window.0) {
+            Some(val) if val.restored => val.to_owned(),
+
+            _ => return,
+        };
+
+        if self
+            .ignored_combinations
+            .contains(&(desktop_id, monitor_handle.0))
+        {
+            return;
+        }
+
+        let workspace = match self.workspaces.get(&(desktop_id, monitor_handle.0)) {
+            Some(val) => val,
+
+            None => return,
+        };
+
+        let layout = &mut self.layouts.get_mut(&monitor_handle.0).unwrap()[workspace.layout_idx];
+
+        adjust(&mut layout.get_variants_mut()[workspace.variant_idx]);
+
+        self.update_workspace(desktop_id, monitor_handle);
+    }
+
+    fn get_oldest_window_idx(&self, workspace: &Workspace) -> usize {
+        let mut ret: Option<(usize, &std::time::Instant)> = None;
+
+        for (i, hwnd) in workspace.managed_window_handles.iter().enumerate() {
+            if let Some(since) = self.managed_since.get(&hwnd.0) {
+                if ret.is_none_or(|(_, oldest)| since < oldest) {
+                    ret = Some((i, since));
+                }
+            }
+        }
+
+        return match ret {
+            Some((i, _)) => i,
+
+            None => 0,
+        };
+    }
+
+    fn get_spill_target(&self, guid: GUID, hmonitor: HMONITOR) -> Option<HMONITOR> {
+        for other in self.monitor_handles.iter() {
+            if *other == hmonitor {
+                continue;
+            }
+
+            let has_room = match self.workspaces.get(&(guid, other.0)) {
+                Some(workspace) => self.layouts.get(&other.0).unwrap()[workspace.layout_idx]
+                    .get_variants()[workspace.variant_idx]
+                    .has_room_for(workspace.managed_window_handles.len()),
+
+                None => true,
+            };
+
+            if has_room {
+                return Some(*other);
+            }
+        }
+
+        return None;
+    }
+
+    unsafe fn enforce_window_limit(&mut self, guid: GUID, hmonitor: HMONITOR) {
+        let workspace = match self.workspaces.get(&(guid, hmonitor.0)) {
+            Some(w) => w,
+
+            None => return,
+        };
+
+        let variant = &self.layouts.get(&hmonitor.0).unwrap()[workspace.layout_idx].get_variants()
+            [workspace.variant_idx];
+
+        let overflow_len = variant.overflow_len(workspace.managed_window_handles.len());
+
+        let policy = variant.get_overflow_policy().clone();
+
+        for _ in 0..overflow_len {
+            let workspace = self.workspaces.get(&(guid, hmonitor.0)).unwrap();
+
+            let idx = match policy {
+                OverflowPolicy::MinimiseOldest => self.get_oldest_window_idx(workspace),
+
+                _ => workspace.managed_window_handles.len() - 1,
+            };
+
+            if policy == OverflowPolicy::Spill {
+                if let Some(other) = self.get_spill_target(guid, hmonitor) {
+                    let other_idx = match self.workspaces.get(&(guid, other.0)) {
+                        Some(val) => val.managed_window_handles.len(),
+
+                        None => 0,
+                    };
+
+                    self.move_windows_across_monitors(guid, hmonitor, other, idx, other_idx);
+
+                    self.update_workspace(guid, other);
+
+                    continue;
+                }
+            }
+
+            let hwnd = match self.remove_hwnd(guid, hmonitor, idx) {
+                Some(val) => val,
+
+                None => return,
+            };
+
+            if policy == OverflowPolicy::MinimiseOldest {
+                if let Some(window_info) = self.window_info.get_mut(&hwnd.0) {
+                    window_info.restored = false;
+                }
+
+                let _ = ShowWindowAsync(hwnd, SW_MINIMIZE);
+            } else {
+                self.ignored_windows.insert(hwnd.0);
+
+                self.overflow_windows.push(hwnd.0);
+            }
+        }
+    }
+
+    unsafe fn restore_overflow_windows(&mut self, guid: GUID, hmonitor: HMONITOR) {
+        loop {
+            let windows_len = match self.workspaces.get(&(guid, hmonitor.0)) {
+                Some(workspace) => {
+                    if !self.layouts.get(&hmonitor.0).unwrap()[workspace.layout_idx].get_variants()
+                        [workspace.variant_idx]
+                        .has_room_for(workspace.managed_window_handles.len())
+                    {
+                        return;
+                    }
+
+                    workspace.managed_window_handles.len()
+                }
+
+                None => 0,
+            };
+
+            let i = match self.overflow_windows.iter().position(|h| {
+                self.window_info.get(h).is_some_and(|info| {
+                    info.desktop_id == guid && info.monitor_handle == hmonitor && info.restored
+                })
+            }) {
+                Some(val) => val,
+
+                None => return,
+            };
+
+            let hwnd = HWND(self.overflow_windows.remove(i));
+
+            self.ignored_windows.remove(&hwnd.0);
+
+            self.insert_hwnd(guid, hmonitor, windows_len, hwnd);
+        }
+    }
+
     unsafe fn update_workspace(&mut self, guid: GUID, hmonitor: HMONITOR) {
         if self.ignored_combinations.contains(&(guid, hmonitor.0)) {
             return;
         }
 
+        self.enforce_window_limit(guid, hmonitor);
+
+        self.restore_overflow_windows(guid, hmonitor);
+
         let workspace = match self.workspaces.get(&(guid, hmonitor.0)) {
             Some(w) => w,
 
@@ -1379,7 +1707,9 @@ impl WindowManager {
 
             variant.update(
                 self.settings.window_padding,
-                self.settings.edge_padding,
+                &self.monitor_settings.get(&hmonitor.0).unwrap().edge_padding,
+                &self.settings.frame_insets,
+                self.settings.smart_gaps,
                 &monitor_rect,
             );
         }
@@ -1389,15 +1719,7 @@ impl WindowManager {
         let positions = variant.get_positions_at(workspace.managed_window_handles.len() - 1);
 
         for (i, hwnd) in workspace.managed_window_handles.iter().enumerate() {
-            match SetWindowPos(
-                *hwnd,
-                None,
-                positions[i].x,
-                positions[i].y,
-                positions[i].cx,
-                positions[i].cy,
-                SWP_NOZORDER,
-            ) {
+            match set_window_position(*hwnd, &positions[i], &self.settings) {
                 Ok(_) => continue,
 
                 Err(_) => {
@@ -1424,6 +1746,8 @@ impl WindowManager {
             }
 
             self.update_workspace(guid, hmonitor);
+        } else if self.settings.smart_gaps && self.settings.smart_gaps_hide_border {
+            self.update_borders(guid, hmonitor);
         }
     }
 
@@ -1484,24 +1808,80 @@ impl WindowManager {
     }
 
     unsafe fn set_border_to_unfocused(&self, hwnd: HWND) {
+        let border_colour = if self.is_lone_window(hwnd) {
+            COLORREF(DWMWA_COLOR_NONE)
+        } else {
+            self.settings.get_unfocused_border_colour()
+        };
+
         let _ = DwmSetWindowAttribute(
             hwnd,
             DWMWA_BORDER_COLOR,
-            &self.settings.get_unfocused_border_colour() as *const COLORREF
-                as *const core::ffi::c_void,
-            std::mem::size_of_val(&self.settings.get_unfocused_border_colour()) as u32,
+            &border_colour as *const COLORREF as *const core::ffi::c_void,
+            std::mem::size_of_val(&border_colour) as u32,
         );
     }
 
     unsafe fn set_border_to_focused(&self, hwnd: HWND) {
+        let border_colour = if self.is_lone_window(hwnd) {
+            COLORREF(DWMWA_COLOR_NONE)
+        } else {
+            self.settings.focused_border_colour
+        };
+
         let _ = DwmSetWindowAttribute(
             hwnd,
             DWMWA_BORDER_COLOR,
-            &self.settings.focused_border_colour as *const COLORREF as *const core::ffi::c_void,
-            std::mem::size_of_val(&self.settings.focused_border_colour) as u32,
+            &border_colour as *const COLORREF as *const core::ffi::c_void,
+            std::mem::size_of_val(&border_colour) as u32,
         );
     }
 
+    fn is_lone_window(&self, hwnd: HWND) -> bool {
+        if !self.settings.smart_gaps || !self.settings.smart_gaps_hide_border {
+            return false;
+        }
+
+        let WindowInfo {
+            desktop_id,
+            monitor_handle,
+            ..
+        } = match self.window_info.get(&hwnd.0) {
+            Some(val) => val,
+
+            None => return false,
+        };
+
+        if self
+            .ignored_combinations
+            .contains(&(*desktop_id, monitor_handle.0))
+        {
+            return false;
+        }
+
+        match self.workspaces.get(&(*desktop_id, monitor_handle.0)) {
+            Some(workspace) => return workspace.managed_window_handles == vec![hwnd],
+
+            None => return false,
+        }
+    }
+
+    unsafe fn update_borders(&self, guid: GUID, hmonitor: HMONITOR) {
+        let workspace = match self.workspaces.get(&(guid, hmonitor.0)) {
+            Some(val) => val,
+
+            None => return,
+        };
+
+        for hwnd in workspace.managed_window_handles.iter() {
+            if self.foreground_window == Some(*hwnd) {
+                self.set_border_to_focused(*hwnd);
+            } else {
+                self.set_border_to_unfocused(*hwnd);
+            }
+        }
+    }
+
     unsafe fn initialize_border(&self, hwnd: HWND) {
         let corner_preference = if self.settings.disable_rounding {
             DWMWCP_DONOTROUND
@@ -1538,11 +1918,11 @@ impl WindowManager {
             None => {
                 if window_info.restored {
                     self.workspaces.insert(
-                        (window_info.desktop_id, window_info.monitor_handle.0),
+                        (guid, hmonitor.0),
                         Workspace::new(
                             hwnd,
                             self.settings.default_layout_idx,
-                            self.layouts.get(&window_info.monitor_handle.0).unwrap()
+                            self.layouts.get(&hmonitor.0).unwrap()
                                 [self.settings.default_layout_idx]
                                 .default_variant_idx(),
                         ),
@@ -1737,6 +2117,9 @@ impl WindowManager {
 
         wm.layouts.insert(hmonitor.0, Vec::new());
 
+        wm.monitor_settings
+            .insert(hmonitor.0, wm.settings.get_monitor_settings(hmonitor));
+
         return true.into();
     }
 }
@@ -1752,69 +2135,113 @@ unsafe fn has_sizebox(hwnd: HWND) -> bool {
     GetWindowLongPtrA(hwnd, GWL_STYLE) & WS_SIZEBOX.0 as isize != 0
 }
 
-pub unsafe fn convert_for_monitor(layout: &Layout, hmonitor: HMONITOR) -> Option<Layout> {
-    let mut monitor_info = MONITORINFO::default();
+unsafe fn get_process_name(hwnd: HWND) -> Option<String> {
+    let mut process_id = 0;
 
-    monitor_info.cbSize = std::mem::size_of::<MONITORINFO>() as u32;
+    GetWindowThreadProcessId(hwnd, Some(&mut process_id));
 
-    let _ = GetMonitorInfoA(hmonitor, &mut monitor_info);
+    let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id).ok()?;
 
-    let monitor_rect = Zone::from(monitor_info.rcWork);
+    let mut buffer = [0u16; 260];
 
-    let variant_monitor_rect = layout.get_monitor_rect();
+    let mut len = buffer.len() as u32;
 
-    if &monitor_rect == variant_monitor_rect {
-        return None;
-    }
+    let result = QueryFullProcessImageNameW(
+        process,
+        PROCESS_NAME_WIN32,
+        PWSTR(buffer.as_mut_ptr()),
+        &mut len,
+    );
 
-    let original_width = (variant_monitor_rect.right - variant_monitor_rect.left) as f64;
+    let _ = CloseHandle(process);
 
-    let original_height = (variant_monitor_rect.bottom - variant_monitor_rect.top) as f64;
+    result.ok()?;
 
-    let new_width = (monitor_rect.right - monitor_rect.left) as f64;
+    let path = String::from_utf16_lossy(&buffer[..len as usize]);
 
-    let new_height = (monitor_rect.bottom - monitor_rect.top) as f64;
+    return std::path::Path::new(&path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_lowercase());
+}
 
-    let mut ret = layout.clone();
+unsafe fn get_dwm_frame_insets(hwnd: HWND) -> Option<FrameInsets> {
+    let mut window_rect = RECT::default();
 
-    for l in ret.get_variants_mut().iter_mut() {
-        for zones in l.get_zones_mut().iter_mut() {
-            for zone in zones {
-                zone.left -= variant_monitor_rect.left;
+    GetWindowRect(hwnd, &mut window_rect).ok()?;
 
-                zone.top -= variant_monitor_rect.top;
+    let mut frame_rect = RECT::default();
 
-                zone.right -= variant_monitor_rect.left;
+    DwmGetWindowAttribute(
+        hwnd,
+        DWMWA_EXTENDED_FRAME_BOUNDS,
+        &mut frame_rect as *mut RECT as *mut core::ffi::c_void,
+        std::mem::size_of::<RECT>() as u32,
+    )
+    .ok()?;
 
-                zone.bottom -= variant_monitor_rect.top;
+    return Some(FrameInsets {
+        left: frame_rect.left - window_rect.left,
+        top: frame_rect.top - window_rect.top,
+        right: window_rect.right - frame_rect.right,
+        bottom: window_rect.bottom - frame_rect.bottom,
+    });
+}
 
-                if new_width != original_width {
-                    zone.left = ((zone.left as f64 * new_width) / original_width).round() as i32;
+unsafe fn set_window_position(hwnd: HWND, position: &Position, settings: &Settings) -> Result<()> {
+    let position =
+        position.with_frame_insets(&settings.frame_insets, &settings.get_frame_insets(hwnd));
 
-                    zone.right = ((zone.right as f64 * new_width) / original_width).round() as i32;
-                }
+    return SetWindowPos(
+        hwnd,
+        None,
+        position.x,
+        position.y,
+        position.cx,
+        position.cy,
+        SWP_NOZORDER,
+    );
+}
 
-                if new_height != original_height {
-                    zone.top = ((zone.top as f64 * new_height) / original_height).round() as i32;
+unsafe fn get_monitor_name(hmonitor: HMONITOR) -> Option<String> {
+    let mut monitor_info = MONITORINFOEXW::default();
 
-                    zone.bottom =
-                        ((zone.bottom as f64 * new_height) / original_height).round() as i32;
-                }
+    monitor_info.monitorInfo.cbSize = std::mem::size_of::<MONITORINFOEXW>() as u32;
 
-                zone.left += (&monitor_rect).left;
+    if !GetMonitorInfoW(hmonitor, &mut monitor_info.monitorInfo).as_bool() {
+        return None;
+    }
 
-                zone.top += (&monitor_rect).top;
+    let len = monitor_info
+        .szDevice
+        .iter()
+        .position(|c| *c == 0)
+        .unwrap_or(monitor_info.szDevice.len());
 
-                zone.right += (&monitor_rect).left;
+    return Some(String::from_utf16_lossy(&monitor_info.szDevice[..len]));
+}
 
-                zone.bottom += (&monitor_rect).top;
-            }
-        }
-    }
+/// # Safety
+///
+/// `hmonitor` must be a valid monitor handle.
+pub unsafe fn convert_for_monitor(
+    layout: &Layout,
+    hmonitor: HMONITOR,
+    struts: &EdgePadding,
+) -> Option<Layout> {
+    let mut monitor_info = MONITORINFO {
+        cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+        ..Default::default()
+    };
 
-    ret.set_monitor_rect(monitor_rect.clone());
+    let _ = GetMonitorInfoA(hmonitor, &mut monitor_info);
+
+    let monitor_rect = Zone::from(monitor_info.rcWork).shrunk(struts);
 
-    return Some(ret);
+    if &monitor_rect == layout.get_monitor_rect() {
+        return None;
+    }
+
+    return Some(layout.scaled_to(&monitor_rect));
 }
 
 pub unsafe fn register_hotkeys() {
@@ -1919,6 +2346,41 @@ pub unsafe fn register_hotkeys() {
         MOD_ALT | MOD_SHIFT | MOD_NOREPEAT,
         0x4F,
     );
+
+    let _shrink_master = RegisterHotKey(
+        None,
+        hotkey_identifiers::SHRINK_MASTER as i32,
+        MOD_ALT | MOD_CONTROL,
+        0x48,
+    );
+
+    let _grow_master = RegisterHotKey(
+        None,
+        hotkey_identifiers::GROW_MASTER as i32,
+        MOD_ALT | MOD_CONTROL,
+        0x4C,
+    );
+
+    let _remove_master = RegisterHotKey(
+        None,
+        hotkey_identifiers::REMOVE_MASTER as i32,
+        MOD_ALT | MOD_CONTROL,
+        0x4A,
+    );
+
+    let _add_master = RegisterHotKey(
+        None,
+        hotkey_identifiers::ADD_MASTER as i32,
+        MOD_ALT | MOD_CONTROL,
+        0x4B,
+    );
+
+    let _cycle_stack = RegisterHotKey(
+        None,
+        hotkey_identifiers::CYCLE_STACK as i32,
+        MOD_ALT | MOD_CONTROL,
+        0x49,
+    );
 }
 
 pub unsafe fn handle_message(msg: MSG, wm: &mut WindowManager) {
@@ -2020,6 +2482,26 @@ pub unsafe fn handle_message(msg: MSG, wm: &mut WindowManager) {
                 wm.toggle_workspace();
             }
 
+            hotkey_identifiers::SHRINK_MASTER => {
+                wm.adjust_master(|variant| variant.adjust_master_ratio(-0.05));
+            }
+
+            hotkey_identifiers::GROW_MASTER => {
+                wm.adjust_master(|variant| variant.adjust_master_ratio(0.05));
+            }
+
+            hotkey_identifiers::REMOVE_MASTER => {
+                wm.adjust_master(|variant| variant.adjust_master_count(-1));
+            }
+
+            hotkey_identifiers::ADD_MASTER => {
+                wm.adjust_master(|variant| variant.adjust_master_count(1));
+            }
+
+            hotkey_identifiers::CYCLE_STACK => {
+                wm.cycle_stack();
+            }
+
             _ => (),
         },
 
@@ -2028,6 +2510,18 @@ pub unsafe fn handle_message(msg: MSG, wm: &mut WindowManager) {
 }
 
 pub unsafe fn show_error_message(message: &str) {
+    show_console_message(message, "Press ENTER to exit");
+}
+
+pub fn show_warning_message(message: &str) {
+    unsafe {
+        show_console_message(message, "Press ENTER to continue");
+
+        let _free_console = FreeConsole();
+    }
+}
+
+unsafe fn show_console_message(message: &str, prompt: &str) {
     let _free_console = FreeConsole();
 
     let _alloc_console = AllocConsole();
@@ -2042,7 +2536,7 @@ pub unsafe fn show_error_message(message: &str) {
 
     println!("{}", message);
 
-    println!("Press ENTER to exit");
+    println!("{}", prompt);
 
     let mut buf = String::new();
 
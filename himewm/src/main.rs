@@ -18,7 +18,7 @@ fn main() {
     let mut msg = MSG::default();
 
     unsafe {
-        let layouts = match init::initialize_layouts() {
+        let layouts = match init::initialize_layouts(&user_settings) {
             Some(val) => val,
 
             None => {
@@ -30,7 +30,7 @@ fn main() {
 
         himewm::register_hotkeys();
 
-        let _create_tray_icon = tray_menu::create();
+        let tray_icon = tray_menu::create();
 
         tray_menu::set_menu_event_handler();
 
@@ -38,9 +38,21 @@ fn main() {
 
         wm.initialize(layouts.into_iter().map(|(_, layout)| layout).collect());
 
+        let mut active_label = None;
+
         while GetMessageA(&mut msg, None, 0, 0).as_bool() {
             himewm::handle_message(msg, &mut wm);
 
+            let label = wm.get_active_label();
+
+            if label != active_label {
+                if let Ok(tray_icon) = &tray_icon {
+                    tray_menu::set_active_label(tray_icon, label.as_deref());
+                }
+
+                active_label = label;
+            }
+
             let _translate_message = TranslateMessage(&msg);
 
             DispatchMessageA(&msg);
@@ -26,6 +26,14 @@ pub fn create() -> tray_icon::Result<TrayIcon> {
         .build();
 }
 
+pub fn set_active_label(tray_icon: &TrayIcon, label: Option<&str>) {
+    let _set_tooltip = match label {
+        Some(val) => tray_icon.set_tooltip(Some(format!("himewm - {}", val))),
+
+        None => tray_icon.set_tooltip(Some("himewm")),
+    };
+}
+
 pub unsafe fn set_menu_event_handler() {
     MenuEvent::set_event_handler(Some(|event: MenuEvent| match event.id().as_ref() {
         menu_ids::QUIT => {
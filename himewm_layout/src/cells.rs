@@ -0,0 +1,187 @@
+use crate::*;
+
+fn cell_coordinate(start: i32, len: i32, k: usize, n: usize) -> i32 {
+    start + ((k as f64 * len as f64) / n as f64).round() as i32
+}
+
+pub(crate) fn grid_cells(
+    rect: &Zone,
+    cells_len: usize,
+    preference: &GridPreference,
+    fill: &GridFill,
+) -> Vec<Zone> {
+    let mut per_line = 1;
+
+    while per_line * per_line < cells_len {
+        per_line += 1;
+    }
+
+    let lines = cells_len.div_ceil(per_line);
+
+    let mut ret = Vec::new();
+
+    for line in 0..lines {
+        let line_len = std::cmp::min(per_line, cells_len - line * per_line);
+
+        let divisions = match fill {
+            GridFill::Stretch => line_len,
+            GridFill::ExpandLast => per_line,
+        };
+
+        for k in 0..line_len {
+            let end = match k == line_len - 1 {
+                true => divisions,
+                false => k + 1,
+            };
+
+            match preference {
+                GridPreference::Columns => ret.push(Zone::new(
+                    cell_coordinate(rect.left, rect.w(), k, divisions),
+                    cell_coordinate(rect.top, rect.h(), line, lines),
+                    cell_coordinate(rect.left, rect.w(), end, divisions),
+                    cell_coordinate(rect.top, rect.h(), line + 1, lines),
+                )),
+
+                GridPreference::Rows => ret.push(Zone::new(
+                    cell_coordinate(rect.left, rect.w(), line, lines),
+                    cell_coordinate(rect.top, rect.h(), k, divisions),
+                    cell_coordinate(rect.left, rect.w(), line + 1, lines),
+                    cell_coordinate(rect.top, rect.h(), end, divisions),
+                )),
+            }
+        }
+    }
+
+    return ret;
+}
+
+pub(crate) fn stacked_cells(rect: &Zone, cells_len: usize, direction: &Direction) -> Vec<Zone> {
+    let mut ret = Vec::new();
+
+    for k in 0..cells_len {
+        match direction {
+            Direction::Horizontal => ret.push(Zone::new(
+                cell_coordinate(rect.left, rect.w(), k, cells_len),
+                rect.top,
+                cell_coordinate(rect.left, rect.w(), k + 1, cells_len),
+                rect.bottom,
+            )),
+
+            Direction::Vertical | Direction::Auto => ret.push(Zone::new(
+                rect.left,
+                cell_coordinate(rect.top, rect.h(), k, cells_len),
+                rect.right,
+                cell_coordinate(rect.top, rect.h(), k + 1, cells_len),
+            )),
+        }
+    }
+
+    return ret;
+}
+
+pub(crate) fn master_and_stack(rect: &Zone, master_ratio: f64, master_side: Edge) -> (Zone, Zone) {
+    let master_w = (master_ratio * rect.w() as f64).round() as i32;
+
+    let master_h = (master_ratio * rect.h() as f64).round() as i32;
+
+    return match master_side {
+        Edge::Left => (
+            Zone::new(rect.left, rect.top, rect.left + master_w, rect.bottom),
+            Zone::new(rect.left + master_w, rect.top, rect.right, rect.bottom),
+        ),
+
+        Edge::Top => (
+            Zone::new(rect.left, rect.top, rect.right, rect.top + master_h),
+            Zone::new(rect.left, rect.top + master_h, rect.right, rect.bottom),
+        ),
+
+        Edge::Right => (
+            Zone::new(rect.right - master_w, rect.top, rect.right, rect.bottom),
+            Zone::new(rect.left, rect.top, rect.right - master_w, rect.bottom),
+        ),
+
+        Edge::Bottom => (
+            Zone::new(rect.left, rect.bottom - master_h, rect.right, rect.bottom),
+            Zone::new(rect.left, rect.top, rect.right, rect.bottom - master_h),
+        ),
+    };
+}
+
+pub(crate) fn master_stack_cells(
+    rect: &Zone,
+    cells_len: usize,
+    master_count: usize,
+    master_ratio: f64,
+    stack_direction: &Direction,
+    master_side: Edge,
+    auto_split_bias: f64,
+) -> Vec<Zone> {
+    if master_count == 0 || cells_len <= master_count {
+        return stacked_cells(
+            rect,
+            cells_len,
+            &stack_direction.resolve(rect, auto_split_bias),
+        );
+    }
+
+    let (master, stack) = master_and_stack(rect, master_ratio, master_side);
+
+    let mut ret = stacked_cells(
+        &master,
+        master_count,
+        &stack_direction.resolve(&master, auto_split_bias),
+    );
+
+    ret.append(&mut stacked_cells(
+        &stack,
+        cells_len - master_count,
+        &stack_direction.resolve(&stack, auto_split_bias),
+    ));
+
+    return ret;
+}
+
+pub(crate) fn centered_master_cells(rect: &Zone, cells_len: usize, center_ratio: f64) -> Vec<Zone> {
+    if cells_len == 1 {
+        return vec![rect.clone()];
+    }
+
+    let side_w = ((1.0 - center_ratio) * rect.w() as f64 / 2.0).round() as i32;
+
+    let right_len = cells_len / 2;
+
+    let left_len = (cells_len - 1) / 2;
+
+    let master_left = match left_len {
+        0 => rect.left,
+        _ => rect.left + side_w,
+    };
+
+    let mut ret = vec![Zone::new(
+        master_left,
+        rect.top,
+        rect.right - side_w,
+        rect.bottom,
+    )];
+
+    let right = stacked_cells(
+        &Zone::new(rect.right - side_w, rect.top, rect.right, rect.bottom),
+        right_len,
+        &Direction::Vertical,
+    );
+
+    let left = stacked_cells(
+        &Zone::new(rect.left, rect.top, rect.left + side_w, rect.bottom),
+        left_len,
+        &Direction::Vertical,
+    );
+
+    for k in 0..(cells_len - 1) {
+        match k % 2 {
+            0 => ret.push(right[k / 2].clone()),
+            _ => ret.push(left[k / 2].clone()),
+        }
+    }
+
+    return ret;
+}
@@ -0,0 +1,1351 @@
+use crate::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DslError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for DslError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for DslError {}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(String),
+    Str(String),
+    Symbol(char),
+    Arrow,
+    End,
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Ident(val) | Token::Number(val) => write!(f, "'{}'", val),
+
+            Token::Str(val) => write!(f, "{}", quote(val)),
+
+            Token::Symbol(val) => write!(f, "'{}'", val),
+
+            Token::Arrow => write!(f, "'=>'"),
+
+            Token::End => write!(f, "end of line"),
+        }
+    }
+}
+
+fn quote(val: &str) -> String {
+    format!("\"{}\"", val.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn tokenize(src: &str, line: usize) -> Result<Vec<(Token, usize)>, DslError> {
+    let mut ret = Vec::new();
+
+    let chars = src.chars().collect::<Vec<char>>();
+
+    let mut idx = 0;
+
+    while idx < chars.len() {
+        let column = idx + 1;
+
+        match chars[idx] {
+            '#' => break,
+
+            c if c.is_whitespace() => idx += 1,
+
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                let start = idx;
+
+                while idx < chars.len() && (chars[idx].is_ascii_alphanumeric() || chars[idx] == '_')
+                {
+                    idx += 1;
+                }
+
+                ret.push((Token::Ident(chars[start..idx].iter().collect()), column));
+            }
+
+            c if c.is_ascii_digit() || c == '.' => {
+                let start = idx;
+
+                while idx < chars.len() && (chars[idx].is_ascii_digit() || chars[idx] == '.') {
+                    idx += 1;
+                }
+
+                ret.push((Token::Number(chars[start..idx].iter().collect()), column));
+            }
+
+            '"' => {
+                let mut val = String::new();
+
+                idx += 1;
+
+                loop {
+                    match chars.get(idx) {
+                        Some('"') => break,
+
+                        Some('\\') if idx + 1 < chars.len() => {
+                            val.push(chars[idx + 1]);
+
+                            idx += 2;
+                        }
+
+                        Some(c) => {
+                            val.push(*c);
+
+                            idx += 1;
+                        }
+
+                        None => {
+                            return Err(DslError {
+                                line,
+                                column,
+                                message: String::from("unterminated string"),
+                            })
+                        }
+                    }
+                }
+
+                ret.push((Token::Str(val), column));
+
+                idx += 1;
+            }
+
+            '=' if chars.get(idx + 1) == Some(&'>') => {
+                ret.push((Token::Arrow, column));
+
+                idx += 2;
+            }
+
+            c @ ('[' | ']' | '|' | '/' | ';' | '(' | ')' | ',' | '=' | '*' | '!') => {
+                ret.push((Token::Symbol(c), column));
+
+                idx += 1;
+            }
+
+            c => {
+                return Err(DslError {
+                    line,
+                    column,
+                    message: format!("unexpected character '{}'", c),
+                })
+            }
+        }
+    }
+
+    ret.push((Token::End, chars.len() + 1));
+
+    return Ok(ret);
+}
+
+struct Arg {
+    key: String,
+    value: String,
+    line: usize,
+    key_column: usize,
+    value_column: usize,
+}
+
+impl Arg {
+    fn error<T>(&self, expected: &str) -> Result<T, DslError> {
+        Err(DslError {
+            line: self.line,
+            column: self.value_column,
+            message: format!(
+                "'{}' expects {}, found '{}'",
+                self.key, expected, self.value
+            ),
+        })
+    }
+
+    fn usize(&self) -> Result<usize, DslError> {
+        match self.value.parse() {
+            Ok(val) => Ok(val),
+
+            Err(_) => self.error("a whole number"),
+        }
+    }
+
+    fn i32(&self) -> Result<i32, DslError> {
+        match self.value.parse() {
+            Ok(val) => Ok(val),
+
+            Err(_) => self.error("a whole number"),
+        }
+    }
+
+    fn ratio(&self) -> Result<f64, DslError> {
+        match self.value.parse::<f64>() {
+            Ok(val) if val > 0.0 && val < 1.0 => Ok(val),
+
+            _ => self.error("a ratio between 0 and 1"),
+        }
+    }
+
+    fn bias(&self) -> Result<f64, DslError> {
+        match self.value.parse::<f64>() {
+            Ok(val) if val > 0.0 && val.is_finite() => Ok(val),
+
+            _ => self.error("a positive number"),
+        }
+    }
+
+    fn bool(&self) -> Result<bool, DslError> {
+        match self.value.as_str() {
+            "true" => Ok(true),
+
+            "false" => Ok(false),
+
+            _ => self.error("'true' or 'false'"),
+        }
+    }
+
+    fn direction(&self) -> Result<Direction, DslError> {
+        match self.value.as_str() {
+            "h" => Ok(Direction::Horizontal),
+
+            "v" => Ok(Direction::Vertical),
+
+            "a" => Ok(Direction::Auto),
+
+            _ => self.error("'h', 'v' or 'a'"),
+        }
+    }
+
+    fn edge(&self) -> Result<Edge, DslError> {
+        match self.value.as_str() {
+            "left" => Ok(Edge::Left),
+
+            "top" => Ok(Edge::Top),
+
+            "right" => Ok(Edge::Right),
+
+            "bottom" => Ok(Edge::Bottom),
+
+            _ => self.error("'left', 'top', 'right' or 'bottom'"),
+        }
+    }
+
+    fn grid_preference(&self) -> Result<GridPreference, DslError> {
+        match self.value.as_str() {
+            "rows" => Ok(GridPreference::Rows),
+
+            "columns" => Ok(GridPreference::Columns),
+
+            _ => self.error("'rows' or 'columns'"),
+        }
+    }
+
+    fn grid_fill(&self) -> Result<GridFill, DslError> {
+        match self.value.as_str() {
+            "stretch" => Ok(GridFill::Stretch),
+
+            "expand_last" => Ok(GridFill::ExpandLast),
+
+            _ => self.error("'stretch' or 'expand_last'"),
+        }
+    }
+
+    fn overflow_policy(&self) -> Result<OverflowPolicy, DslError> {
+        match self.value.as_str() {
+            "float" => Ok(OverflowPolicy::Float),
+
+            "stack" => Ok(OverflowPolicy::Stack),
+
+            "spill" => Ok(OverflowPolicy::Spill),
+
+            "minimise_oldest" => Ok(OverflowPolicy::MinimiseOldest),
+
+            _ => self.error("'float', 'stack', 'spill' or 'minimise_oldest'"),
+        }
+    }
+
+    fn min_size_overflow(&self) -> Result<MinSizeOverflow, DslError> {
+        match self.value.as_str() {
+            "stack" => Ok(MinSizeOverflow::Stack),
+
+            "switch_direction" => Ok(MinSizeOverflow::SwitchDirection),
+
+            _ => self.error("'stack' or 'switch_direction'"),
+        }
+    }
+
+    fn unknown<T>(&self, clause: &str) -> Result<T, DslError> {
+        Err(DslError {
+            line: self.line,
+            column: self.key_column,
+            message: format!("'{}' does not take '{}'", clause, self.key),
+        })
+    }
+}
+
+struct Clause {
+    name: String,
+    column: usize,
+    args: Vec<Arg>,
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    idx: usize,
+    line: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.idx].0
+    }
+
+    fn column(&self) -> usize {
+        self.tokens[self.idx].1
+    }
+
+    fn next(&mut self) -> (Token, usize) {
+        let ret = self.tokens[self.idx].clone();
+
+        if self.idx < self.tokens.len() - 1 {
+            self.idx += 1;
+        }
+
+        return ret;
+    }
+
+    fn error<T>(&self, column: usize, message: String) -> Result<T, DslError> {
+        Err(DslError {
+            line: self.line,
+            column,
+            message,
+        })
+    }
+
+    fn unexpected<T>(&self, expected: &str) -> Result<T, DslError> {
+        self.error(
+            self.column(),
+            format!("expected {}, found {}", expected, self.peek()),
+        )
+    }
+
+    fn expect(&mut self, symbol: char) -> Result<(), DslError> {
+        if *self.peek() == Token::Symbol(symbol) {
+            self.next();
+
+            return Ok(());
+        }
+
+        return self.unexpected(&format!("'{}'", symbol));
+    }
+
+    fn ident(&mut self, expected: &str) -> Result<(String, usize), DslError> {
+        match self.next() {
+            (Token::Ident(val), column) => Ok((val, column)),
+
+            (token, column) => {
+                self.error(column, format!("expected {}, found {}", expected, token))
+            }
+        }
+    }
+
+    fn parse_node(&mut self) -> Result<SplitTree, DslError> {
+        match self.next() {
+            (Token::Symbol('*'), _) => Ok(SplitTree::Leaf),
+
+            (Token::Ident(val), column) if val == "h" => {
+                self.parse_container(Direction::Horizontal, '|', column)
+            }
+
+            (Token::Ident(val), column) if val == "v" => {
+                self.parse_container(Direction::Vertical, '/', column)
+            }
+
+            (Token::Ident(val), column) if val == "a" => {
+                self.parse_container(Direction::Auto, ',', column)
+            }
+
+            (token, column) => self.error(
+                column,
+                format!("expected '*', 'h[', 'v[' or 'a[', found {}", token),
+            ),
+        }
+    }
+
+    fn parse_child(&mut self) -> Result<(Option<f64>, SplitTree), DslError> {
+        let weight = match self.peek().clone() {
+            Token::Number(val) => {
+                let column = self.column();
+
+                self.next();
+
+                match val.parse::<f64>() {
+                    Ok(val) if val > 0.0 && val.is_finite() => Some(val),
+
+                    _ => return self.error(column, format!("invalid weight '{}'", val)),
+                }
+            }
+
+            _ => None,
+        };
+
+        let tree = match (weight, self.peek()) {
+            (Some(_), Token::Symbol('*')) | (Some(_), Token::Ident(_)) | (None, _) => {
+                self.parse_node()?
+            }
+
+            (Some(_), _) => SplitTree::Leaf,
+        };
+
+        return Ok((weight, tree));
+    }
+
+    fn parse_container(
+        &mut self,
+        direction: Direction,
+        separator: char,
+        column: usize,
+    ) -> Result<SplitTree, DslError> {
+        self.expect('[')?;
+
+        let mut children = Vec::new();
+
+        loop {
+            children.push(self.parse_child()?);
+
+            match self.peek() {
+                Token::Symbol(']') => {
+                    self.next();
+
+                    break;
+                }
+
+                Token::Symbol(val) if *val == separator => {
+                    self.next();
+                }
+
+                _ => return self.unexpected(&format!("'{}' or ']'", separator)),
+            }
+        }
+
+        return self.to_split_tree(direction, children, column);
+    }
+
+    fn to_split_tree(
+        &self,
+        direction: Direction,
+        mut children: Vec<(Option<f64>, SplitTree)>,
+        column: usize,
+    ) -> Result<SplitTree, DslError> {
+        if children.len() < 2 {
+            return self.error(column, String::from("a split needs at least two children"));
+        }
+
+        let weighted = children
+            .iter()
+            .filter_map(|(weight, _)| *weight)
+            .sum::<f64>();
+
+        let unweighted = children
+            .iter()
+            .filter(|(weight, _)| weight.is_none())
+            .count();
+
+        let (total, fill) = match unweighted {
+            0 => (weighted, 0.0),
+
+            _ if weighted < 1.0 => (1.0, (1.0 - weighted) / unweighted as f64),
+
+            _ => {
+                return self.error(
+                    column,
+                    format!(
+                        "weights add up to {}, leaving no room for unweighted children",
+                        weighted
+                    ),
+                )
+            }
+        };
+
+        let weights = children
+            .iter()
+            .map(|(weight, _)| weight.unwrap_or(fill))
+            .collect::<Vec<f64>>();
+
+        let mut ret = children.pop().unwrap().1;
+
+        let mut rest = weights[weights.len() - 1];
+
+        while children.len() > 1 {
+            let (_, first) = children.pop().unwrap();
+
+            rest += weights[children.len()];
+
+            ret = SplitTree::split(
+                direction.clone(),
+                weights[children.len()] / rest,
+                first,
+                ret,
+            );
+        }
+
+        let (_, first) = children.pop().unwrap();
+
+        return Ok(SplitTree::split(direction, weights[0] / total, first, ret));
+    }
+
+    fn parse_clause(&mut self) -> Result<Clause, DslError> {
+        let (name, column) = self.ident("a clause name")?;
+
+        self.expect('(')?;
+
+        let mut args = Vec::new();
+
+        if *self.peek() == Token::Symbol(')') {
+            self.next();
+
+            return Ok(Clause { name, column, args });
+        }
+
+        loop {
+            let (key, key_column) = self.ident("an argument name")?;
+
+            self.expect('=')?;
+
+            let (value, value_column) = match self.next() {
+                (Token::Ident(val), column)
+                | (Token::Number(val), column)
+                | (Token::Str(val), column) => (val, column),
+
+                (token, column) => {
+                    return self.error(column, format!("expected a value, found {}", token))
+                }
+            };
+
+            args.push(Arg {
+                key,
+                value,
+                line: self.line,
+                key_column,
+                value_column,
+            });
+
+            match self.next() {
+                (Token::Symbol(')'), _) => break,
+
+                (Token::Symbol(','), _) => (),
+
+                (token, column) => {
+                    return self.error(column, format!("expected ',' or ')', found {}", token))
+                }
+            }
+        }
+
+        return Ok(Clause { name, column, args });
+    }
+}
+
+fn end_tiling_behaviour(clause: &Clause) -> Result<Option<EndTilingBehaviour>, DslError> {
+    let mut ret = match clause.name.as_str() {
+        "directional" => EndTilingBehaviour::default_directional(),
+
+        "repeating" => EndTilingBehaviour::default_repeating(),
+
+        "spiral" => EndTilingBehaviour::default_spiral(),
+
+        "dwindle" => EndTilingBehaviour::default_dwindle(),
+
+        "grid" => EndTilingBehaviour::default_grid(),
+
+        "master_stack" => EndTilingBehaviour::default_master_stack(),
+
+        "centered_master" => EndTilingBehaviour::default_centered_master(),
+
+        "monocle" => EndTilingBehaviour::default_monocle(),
+
+        _ => return Ok(None),
+    };
+
+    for arg in clause.args.iter() {
+        match (&mut ret, arg.key.as_str()) {
+            (EndTilingBehaviour::Directional { direction, .. }, "direction")
+            | (
+                EndTilingBehaviour::Spiral {
+                    start_direction: direction,
+                    ..
+                },
+                "start",
+            )
+            | (
+                EndTilingBehaviour::Dwindle {
+                    start_direction: direction,
+                    ..
+                },
+                "start",
+            )
+            | (
+                EndTilingBehaviour::MasterStack {
+                    stack_direction: direction,
+                    ..
+                },
+                "stack",
+            ) => *direction = arg.direction()?,
+
+            (EndTilingBehaviour::Directional { start_from, .. }, "start_from") => {
+                *start_from = arg.usize()?
+            }
+
+            (EndTilingBehaviour::Spiral { ratio, .. }, "ratio")
+            | (EndTilingBehaviour::Dwindle { ratio, .. }, "ratio")
+            | (
+                EndTilingBehaviour::MasterStack {
+                    master_ratio: ratio,
+                    ..
+                },
+                "ratio",
+            )
+            | (
+                EndTilingBehaviour::CenteredMaster {
+                    center_ratio: ratio,
+                    ..
+                },
+                "ratio",
+            ) => *ratio = arg.ratio()?,
+
+            (EndTilingBehaviour::Spiral { clockwise, .. }, "clockwise") => {
+                *clockwise = arg.bool()?
+            }
+
+            (EndTilingBehaviour::Grid { preference, .. }, "prefer") => {
+                *preference = arg.grid_preference()?
+            }
+
+            (EndTilingBehaviour::Grid { fill, .. }, "fill") => *fill = arg.grid_fill()?,
+
+            (EndTilingBehaviour::MasterStack { master_count, .. }, "count") => {
+                *master_count = arg.usize()?
+            }
+
+            (EndTilingBehaviour::MasterStack { master_side, .. }, "side") => {
+                *master_side = arg.edge()?
+            }
+
+            (EndTilingBehaviour::Directional { zone_idx, .. }, "zone")
+            | (EndTilingBehaviour::Repeating { zone_idx, .. }, "zone")
+            | (EndTilingBehaviour::Spiral { zone_idx, .. }, "zone")
+            | (EndTilingBehaviour::Dwindle { zone_idx, .. }, "zone")
+            | (EndTilingBehaviour::Grid { zone_idx, .. }, "zone")
+            | (EndTilingBehaviour::MasterStack { zone_idx, .. }, "zone")
+            | (EndTilingBehaviour::CenteredMaster { zone_idx, .. }, "zone")
+            | (EndTilingBehaviour::Monocle { zone_idx }, "zone") => *zone_idx = arg.usize()?,
+
+            _ => return arg.unknown(&clause.name),
+        }
+    }
+
+    return Ok(Some(ret));
+}
+
+fn apply_metadata(metadata: &mut Metadata, clause: &Clause) -> Result<(), DslError> {
+    for arg in clause.args.iter() {
+        match arg.key.as_str() {
+            "name" => metadata.name = arg.value.clone(),
+
+            "description" => metadata.description = arg.value.clone(),
+
+            "tag" => metadata.tags.push(arg.value.clone()),
+
+            _ => return arg.unknown(&clause.name),
+        }
+    }
+
+    return Ok(());
+}
+
+fn apply_clause(variant: &mut Variant, clause: &Clause, line: usize) -> Result<(), DslError> {
+    match clause.name.as_str() {
+        "meta" => apply_metadata(&mut variant.metadata, clause)?,
+
+        "max_zones" => {
+            for arg in clause.args.iter() {
+                match arg.key.as_str() {
+                    "count" => variant.max_zones = Some(arg.usize()?),
+
+                    _ => return arg.unknown(&clause.name),
+                }
+            }
+        }
+
+        "auto_split" => {
+            for arg in clause.args.iter() {
+                match arg.key.as_str() {
+                    "bias" => variant.auto_split_bias = arg.bias()?,
+
+                    _ => return arg.unknown(&clause.name),
+                }
+            }
+        }
+
+        "max_windows" => {
+            for arg in clause.args.iter() {
+                match arg.key.as_str() {
+                    "count" => variant.max_windows = Some(arg.usize()?),
+
+                    "overflow" => variant.overflow_policy = arg.overflow_policy()?,
+
+                    _ => return arg.unknown(&clause.name),
+                }
+            }
+        }
+
+        "min_size" => {
+            for arg in clause.args.iter() {
+                match arg.key.as_str() {
+                    "width" => variant.min_size.width = arg.i32()?,
+
+                    "height" => variant.min_size.height = arg.i32()?,
+
+                    "overflow" => variant.min_size_overflow = arg.min_size_overflow()?,
+
+                    _ => return arg.unknown(&clause.name),
+                }
+            }
+        }
+
+        "repeat_split" => {
+            let splits = match &mut variant.end_tiling_behaviour {
+                EndTilingBehaviour::Repeating { splits, .. } => splits,
+
+                _ => {
+                    return Err(DslError {
+                        line,
+                        column: clause.column,
+                        message: String::from(
+                            "'repeat_split' needs a 'repeating' clause before it",
+                        ),
+                    })
+                }
+            };
+
+            let mut step = None;
+
+            let mut split = RepeatingSplit::new(Direction::Horizontal, 0.5, 0, false);
+
+            for arg in clause.args.iter() {
+                match arg.key.as_str() {
+                    "step" => step = Some((arg.usize()?, arg)),
+
+                    "direction" => split.direction = arg.direction()?,
+
+                    "ratio" => split.split_ratio = arg.ratio()?,
+
+                    "offset" => split.split_idx_offset = arg.usize()?,
+
+                    "swap" => split.swap = arg.bool()?,
+
+                    _ => return arg.unknown(&clause.name),
+                }
+            }
+
+            match step {
+                Some((val, _)) if val < splits.len() => splits[val].push(split),
+
+                Some((val, _)) if val == splits.len() => splits.push(vec![split]),
+
+                Some((val, arg)) => {
+                    return Err(DslError {
+                        line,
+                        column: arg.value_column,
+                        message: format!("step {} skips step {}", val, splits.len()),
+                    })
+                }
+
+                None => {
+                    return Err(DslError {
+                        line,
+                        column: clause.column,
+                        message: String::from("'repeat_split' needs a 'step'"),
+                    })
+                }
+            }
+        }
+
+        "transform" => {
+            for arg in clause.args.iter() {
+                match arg.key.as_str() {
+                    "mirrored" => variant.transform.mirrored = arg.bool()?,
+
+                    "turns" => variant.transform.quarter_turns = arg.usize()? as u32 % 4,
+
+                    _ => return arg.unknown(&clause.name),
+                }
+            }
+        }
+
+        "zone_min_size" => {
+            let mut zone = None;
+
+            let mut min_size = MinSize::default();
+
+            for arg in clause.args.iter() {
+                match arg.key.as_str() {
+                    "zone" => zone = Some(arg.usize()?),
+
+                    "width" => min_size.width = arg.i32()?,
+
+                    "height" => min_size.height = arg.i32()?,
+
+                    _ => return arg.unknown(&clause.name),
+                }
+            }
+
+            match zone {
+                Some(val) => {
+                    variant.zone_min_sizes.insert(val, min_size);
+                }
+
+                None => {
+                    return Err(DslError {
+                        line,
+                        column: clause.column,
+                        message: String::from("'zone_min_size' needs a 'zone'"),
+                    })
+                }
+            }
+        }
+
+        _ => {
+            return Err(DslError {
+                line,
+                column: clause.column,
+                message: format!("unknown clause '{}'", clause.name),
+            })
+        }
+    }
+
+    return Ok(());
+}
+
+fn without_last_leaf(tree: &SplitTree) -> SplitTree {
+    match tree {
+        SplitTree::Leaf => SplitTree::Leaf,
+
+        SplitTree::Split { first, second, .. } if **second == SplitTree::Leaf => (**first).clone(),
+
+        SplitTree::Split {
+            direction,
+            ratio,
+            first,
+            second,
+        } => SplitTree::split(
+            direction.clone(),
+            *ratio,
+            (**first).clone(),
+            without_last_leaf(second),
+        ),
+    }
+}
+
+enum Line {
+    Empty,
+    Layout(Metadata, usize),
+    Variant(bool, Box<Variant>),
+}
+
+fn parse_line(src: &str, line: usize, monitor_rect: &Zone) -> Result<Line, DslError> {
+    let mut parser = Parser {
+        tokens: tokenize(src, line)?,
+        idx: 0,
+        line,
+    };
+
+    if *parser.peek() == Token::End {
+        return Ok(Line::Empty);
+    }
+
+    if *parser.peek() == Token::Ident(String::from("layout")) {
+        let clause = parser.parse_clause()?;
+
+        if *parser.peek() != Token::End {
+            return parser.unexpected("end of line");
+        }
+
+        let mut metadata = Metadata::default();
+
+        apply_metadata(&mut metadata, &clause)?;
+
+        return Ok(Line::Layout(metadata, clause.column));
+    }
+
+    let is_default = *parser.peek() == Token::Symbol('!');
+
+    if is_default {
+        parser.next();
+    }
+
+    let mut trees = Vec::new();
+
+    let mut columns = Vec::new();
+
+    loop {
+        columns.push(parser.column());
+
+        trees.push(parser.parse_node()?);
+
+        match parser.peek() {
+            Token::Symbol(';') => {
+                parser.next();
+            }
+
+            Token::Arrow | Token::End => break,
+
+            _ => return parser.unexpected("';', '=>' or end of line"),
+        }
+    }
+
+    if trees.len() == 1 && trees[0].leaves_len() > 1 {
+        while trees[0].leaves_len() > 1 {
+            trees.insert(0, without_last_leaf(&trees[0]));
+        }
+
+        columns = vec![columns[0]; trees.len()];
+    }
+
+    let mut clauses = Vec::new();
+
+    if *parser.peek() == Token::Arrow {
+        parser.next();
+
+        while *parser.peek() != Token::End {
+            clauses.push(parser.parse_clause()?);
+        }
+
+        if clauses.is_empty() {
+            return parser.unexpected("a clause");
+        }
+    }
+
+    let mut variant = match Variant::from_split_trees(monitor_rect, trees) {
+        Ok(val) => val,
+
+        Err(LayoutError::WrongZoneCount { i, expected, found }) => {
+            return parser.error(
+                columns[i],
+                format!(
+                    "arrangement {} has {} zones, expected {}",
+                    i + 1,
+                    found,
+                    expected
+                ),
+            )
+        }
+
+        Err(LayoutError::InSplitTree { i, error }) => {
+            return parser.error(columns[i], error.to_string())
+        }
+
+        Err(error) => return parser.error(1, error.to_string()),
+    };
+
+    let mut end_tiling_column = None;
+
+    for clause in clauses.iter() {
+        match end_tiling_behaviour(clause) {
+            Ok(Some(_)) if end_tiling_column.is_some() => {
+                return parser.error(
+                    clause.column,
+                    String::from("only one end tiling clause is allowed"),
+                )
+            }
+
+            Ok(Some(val)) => {
+                variant.end_tiling_behaviour = val;
+
+                end_tiling_column = Some(clause.column);
+            }
+
+            Ok(None) => apply_clause(&mut variant, clause, line)?,
+
+            Err(error) => return Err(error),
+        }
+    }
+
+    variant.materialize_split_trees(monitor_rect);
+
+    if let Err(errors) = variant.validate(monitor_rect) {
+        return parser.error(end_tiling_column.unwrap_or(1), errors[0].to_string());
+    }
+
+    return Ok(Line::Variant(is_default, Box::new(variant)));
+}
+
+fn direction_to_dsl(direction: &Direction) -> &'static str {
+    match direction {
+        Direction::Horizontal => "h",
+
+        Direction::Vertical => "v",
+
+        Direction::Auto => "a",
+    }
+}
+
+fn push_tree_dsl(tree: &SplitTree, dsl: &mut String) {
+    if let SplitTree::Split {
+        direction,
+        ratio,
+        first,
+        second,
+    } = tree
+    {
+        dsl.push_str(&format!("{}[{}", direction_to_dsl(direction), ratio));
+
+        if let SplitTree::Split { .. } = **first {
+            dsl.push(' ');
+
+            push_tree_dsl(first, dsl);
+        }
+
+        match direction {
+            Direction::Horizontal => dsl.push_str(" | "),
+
+            Direction::Vertical => dsl.push_str(" / "),
+
+            Direction::Auto => dsl.push_str(", "),
+        }
+
+        push_tree_dsl(second, dsl);
+
+        dsl.push(']');
+    } else {
+        dsl.push('*');
+    }
+}
+
+fn end_tiling_to_dsl(end_tiling_behaviour: &EndTilingBehaviour) -> Option<Option<String>> {
+    let ret = match end_tiling_behaviour {
+        EndTilingBehaviour::Directional {
+            direction: Direction::Vertical,
+            start_from: 1,
+            from_zones: None,
+            zone_idx: 0,
+        } => return Some(None),
+
+        EndTilingBehaviour::Directional {
+            direction,
+            start_from,
+            from_zones: None,
+            zone_idx,
+        } => format!(
+            "directional(direction={}, start_from={}, zone={})",
+            direction_to_dsl(direction),
+            start_from,
+            zone_idx
+        ),
+
+        EndTilingBehaviour::Directional { .. } => return None,
+
+        EndTilingBehaviour::Repeating { splits, zone_idx } => {
+            let mut clauses = vec![format!("repeating(zone={})", zone_idx)];
+
+            for (step, split_vec) in splits.iter().enumerate() {
+                for split in split_vec.iter() {
+                    clauses.push(format!(
+                        "repeat_split(step={}, direction={}, ratio={}, offset={}, swap={})",
+                        step,
+                        direction_to_dsl(&split.direction),
+                        split.split_ratio,
+                        split.split_idx_offset,
+                        split.swap
+                    ));
+                }
+            }
+
+            clauses.join(" ")
+        }
+
+        EndTilingBehaviour::Spiral {
+            ratio,
+            clockwise,
+            start_direction,
+            zone_idx,
+        } => format!(
+            "spiral(ratio={}, clockwise={}, start={}, zone={})",
+            ratio,
+            clockwise,
+            direction_to_dsl(start_direction),
+            zone_idx
+        ),
+
+        EndTilingBehaviour::Dwindle {
+            ratio,
+            start_direction,
+            zone_idx,
+        } => format!(
+            "dwindle(ratio={}, start={}, zone={})",
+            ratio,
+            direction_to_dsl(start_direction),
+            zone_idx
+        ),
+
+        EndTilingBehaviour::Grid {
+            preference,
+            fill,
+            zone_idx,
+        } => format!(
+            "grid(prefer={}, fill={}, zone={})",
+            match preference {
+                GridPreference::Rows => "rows",
+                GridPreference::Columns => "columns",
+            },
+            match fill {
+                GridFill::Stretch => "stretch",
+                GridFill::ExpandLast => "expand_last",
+            },
+            zone_idx
+        ),
+
+        EndTilingBehaviour::MasterStack {
+            master_count,
+            master_ratio,
+            stack_direction,
+            master_side,
+            zone_idx,
+        } => format!(
+            "master_stack(count={}, ratio={}, stack={}, side={}, zone={})",
+            master_count,
+            master_ratio,
+            direction_to_dsl(stack_direction),
+            match master_side {
+                Edge::Left => "left",
+                Edge::Top => "top",
+                Edge::Right => "right",
+                Edge::Bottom => "bottom",
+            },
+            zone_idx
+        ),
+
+        EndTilingBehaviour::CenteredMaster {
+            center_ratio,
+            zone_idx,
+        } => format!("centered_master(ratio={}, zone={})", center_ratio, zone_idx),
+
+        EndTilingBehaviour::Monocle { zone_idx } => format!("monocle(zone={})", zone_idx),
+    };
+
+    return Some(Some(ret));
+}
+
+fn metadata_to_dsl(clause: &str, metadata: &Metadata) -> Option<String> {
+    let mut args = Vec::new();
+
+    if !metadata.name.is_empty() {
+        args.push(format!("name={}", quote(&metadata.name)));
+    }
+
+    if !metadata.description.is_empty() {
+        args.push(format!("description={}", quote(&metadata.description)));
+    }
+
+    for tag in metadata.tags.iter() {
+        args.push(format!("tag={}", quote(tag)));
+    }
+
+    if args.is_empty() {
+        return None;
+    }
+
+    return Some(format!("{}({})", clause, args.join(", ")));
+}
+
+impl SplitTree {
+    pub fn to_dsl(&self) -> String {
+        let mut ret = String::new();
+
+        push_tree_dsl(self, &mut ret);
+
+        return ret;
+    }
+}
+
+impl Variant {
+    pub fn parse_dsl(src: &str, monitor_rect: &Zone) -> Result<Variant, DslError> {
+        match parse_line(src, 1, monitor_rect)? {
+            Line::Variant(_, val) => Ok(*val),
+
+            _ => Err(DslError {
+                line: 1,
+                column: 1,
+                message: String::from("expected an arrangement"),
+            }),
+        }
+    }
+
+    pub fn to_dsl(&self) -> Option<String> {
+        let trees = self.split_trees.as_ref()?;
+
+        if trees.len() != self.manual_zones_until {
+            return None;
+        }
+
+        let mut ret = trees
+            .iter()
+            .map(|tree| tree.as_ref().map(SplitTree::to_dsl))
+            .collect::<Option<Vec<String>>>()?
+            .join("; ");
+
+        let mut clauses = Vec::new();
+
+        if let Some(val) = metadata_to_dsl("meta", &self.metadata) {
+            clauses.push(val);
+        }
+
+        if let Some(val) = end_tiling_to_dsl(&self.end_tiling_behaviour)? {
+            clauses.push(val);
+        }
+
+        if let Some(val) = self.max_zones {
+            clauses.push(format!("max_zones(count={})", val));
+        }
+
+        if self.auto_split_bias != DEFAULT_AUTO_SPLIT_BIAS {
+            clauses.push(format!("auto_split(bias={})", self.auto_split_bias));
+        }
+
+        if self.max_windows.is_some() || self.overflow_policy != OverflowPolicy::default() {
+            let overflow = match self.overflow_policy {
+                OverflowPolicy::Float => "float",
+                OverflowPolicy::Stack => "stack",
+                OverflowPolicy::Spill => "spill",
+                OverflowPolicy::MinimiseOldest => "minimise_oldest",
+            };
+
+            clauses.push(match self.max_windows {
+                Some(val) => format!("max_windows(count={}, overflow={})", val, overflow),
+
+                None => format!("max_windows(overflow={})", overflow),
+            });
+        }
+
+        if self.min_size != MinSize::default()
+            || self.min_size_overflow != MinSizeOverflow::default()
+        {
+            clauses.push(format!(
+                "min_size(width={}, height={}, overflow={})",
+                self.min_size.width,
+                self.min_size.height,
+                match self.min_size_overflow {
+                    MinSizeOverflow::Stack => "stack",
+                    MinSizeOverflow::SwitchDirection => "switch_direction",
+                }
+            ));
+        }
+
+        for (zone_idx, min_size) in self.zone_min_sizes.iter() {
+            clauses.push(format!(
+                "zone_min_size(zone={}, width={}, height={})",
+                zone_idx, min_size.width, min_size.height
+            ));
+        }
+
+        if !self.transform.is_identity() {
+            clauses.push(format!(
+                "transform(mirrored={}, turns={})",
+                self.transform.mirrored, self.transform.quarter_turns
+            ));
+        }
+
+        if !clauses.is_empty() {
+            ret.push_str(" => ");
+
+            ret.push_str(&clauses.join(" "));
+        }
+
+        return Some(ret);
+    }
+}
+
+impl Layout {
+    pub fn parse_dsl(src: &str, monitor_rect: &Zone) -> Result<Layout, DslError> {
+        let mut variants = Vec::new();
+
+        let mut default_variant_idx = None;
+
+        let mut metadata = None;
+
+        for (i, line) in src.lines().enumerate() {
+            match parse_line(line, i + 1, monitor_rect)? {
+                Line::Empty => (),
+
+                Line::Layout(_, column) if metadata.is_some() => {
+                    return Err(DslError {
+                        line: i + 1,
+                        column,
+                        message: String::from("only one 'layout' line is allowed"),
+                    });
+                }
+
+                Line::Layout(val, _) => metadata = Some(val),
+
+                Line::Variant(is_default, variant) => {
+                    if is_default {
+                        if default_variant_idx.is_some() {
+                            return Err(DslError {
+                                line: i + 1,
+                                column: line.find('!').unwrap_or(0) + 1,
+                                message: String::from("only one variant can be the default"),
+                            });
+                        }
+
+                        default_variant_idx = Some(variants.len());
+                    }
+
+                    variants.push(*variant);
+                }
+            }
+        }
+
+        if variants.is_empty() {
+            return Err(DslError {
+                line: 1,
+                column: 1,
+                message: String::from("layout has no variants"),
+            });
+        }
+
+        return Ok(Layout {
+            version: LAYOUT_VERSION,
+            metadata: metadata.unwrap_or_default(),
+            monitor_rect: monitor_rect.clone(),
+            variants,
+            default_variant_idx: default_variant_idx.unwrap_or(0),
+        });
+    }
+
+    pub fn to_dsl(&self) -> Option<String> {
+        let mut ret = String::new();
+
+        if let Some(val) = metadata_to_dsl("layout", &self.metadata) {
+            ret.push_str(&val);
+
+            ret.push('\n');
+        }
+
+        for (i, variant) in self.variants.iter().enumerate() {
+            if i == self.default_variant_idx && i != 0 {
+                ret.push('!');
+            }
+
+            ret.push_str(&variant.to_dsl()?);
+
+            ret.push('\n');
+        }
+
+        return Some(ret);
+    }
+}
@@ -0,0 +1,651 @@
+use crate::*;
+
+fn check_split_ratio(i: usize, j: usize, split_ratio: f64) -> Result<(), LayoutError> {
+    if split_ratio > 0.0 && split_ratio < 1.0 {
+        return Ok(());
+    } else {
+        return Err(LayoutError::InvalidSplitRatio { i, j, split_ratio });
+    }
+}
+
+fn check_split_position(
+    i: usize,
+    j: usize,
+    zone: &Zone,
+    direction: &SplitDirection,
+) -> Result<(), LayoutError> {
+    let (at, inside) = match direction {
+        SplitDirection::Horizontal(at) => (*at, zone.left < *at && *at < zone.right),
+
+        SplitDirection::Vertical(at) => (*at, zone.top < *at && *at < zone.bottom),
+    };
+
+    if inside {
+        return Ok(());
+    } else {
+        return Err(LayoutError::SplitOutsideZone { i, j, at });
+    }
+}
+
+impl Variant {
+    fn check_zone_vec(&self, i: usize) -> Result<(), LayoutError> {
+        if i < self.zones.len() {
+            return Ok(());
+        } else {
+            return Err(LayoutError::ZoneVecOutOfRange {
+                i,
+                zones_len: self.zones.len(),
+            });
+        }
+    }
+
+    fn check_zone(&self, i: usize, j: usize) -> Result<(), LayoutError> {
+        self.check_zone_vec(i)?;
+
+        if j < self.zones[i].len() {
+            return Ok(());
+        } else {
+            return Err(LayoutError::ZoneOutOfRange {
+                i,
+                j,
+                zones_len: self.zones[i].len(),
+            });
+        }
+    }
+
+    fn merged_zone(&self, i: usize, j: usize, k: usize) -> Result<Zone, LayoutError> {
+        self.check_zone(i, j)?;
+
+        self.check_zone(i, k)?;
+
+        if !self.can_merge_zones(i, j, k) {
+            return Err(LayoutError::ZonesNotAdjacent { i, j, k });
+        }
+
+        let first_zone = &self.zones[i][j];
+
+        let second_zone = &self.zones[i][k];
+
+        return Ok(Zone::new(
+            std::cmp::min(first_zone.left, second_zone.left),
+            std::cmp::min(first_zone.top, second_zone.top),
+            std::cmp::max(first_zone.right, second_zone.right),
+            std::cmp::max(first_zone.bottom, second_zone.bottom),
+        ));
+    }
+
+    fn repeating_split_mut(
+        &mut self,
+        i: usize,
+        j: usize,
+    ) -> Result<&mut RepeatingSplit, LayoutError> {
+        match &mut self.end_tiling_behaviour {
+            EndTilingBehaviour::Repeating {
+                splits,
+                zone_idx: _,
+            } => match splits.get_mut(i).and_then(|split_vec| split_vec.get_mut(j)) {
+                Some(split) => return Ok(split),
+
+                None => return Err(LayoutError::RepeatingSplitOutOfRange { i, j }),
+            },
+
+            _ => return Err(LayoutError::EndTilingBehaviourMismatch),
+        }
+    }
+
+    pub fn try_delete_zones(&mut self, i: usize) -> Result<(), LayoutError> {
+        if i >= self.manual_zones_until {
+            return Err(LayoutError::ZoneVecOutOfRange {
+                i,
+                zones_len: self.manual_zones_until,
+            });
+        }
+
+        if self.manual_zones_until == 1 {
+            return Err(LayoutError::LastZoneVec);
+        }
+
+        self.delete_zones(i);
+
+        return Ok(());
+    }
+
+    pub fn try_insert_zone_vec(
+        &mut self,
+        i: usize,
+        zones: Vec<Zone>,
+        split_tree: Option<SplitTree>,
+    ) -> Result<(), LayoutError> {
+        if i > self.manual_zones_until {
+            return Err(LayoutError::ZoneVecOutOfRange {
+                i,
+                zones_len: self.manual_zones_until,
+            });
+        }
+
+        self.insert_zone_vec(i, zones, split_tree);
+
+        return Ok(());
+    }
+
+    pub fn try_set_zone_vec(
+        &mut self,
+        i: usize,
+        zones: Vec<Zone>,
+        split_tree: Option<SplitTree>,
+    ) -> Result<(), LayoutError> {
+        self.check_zone_vec(i)?;
+
+        self.set_zone_vec(i, zones, split_tree);
+
+        return Ok(());
+    }
+
+    pub fn try_swap_zone_vectors(&mut self, i: usize, j: usize) -> Result<(), LayoutError> {
+        self.check_zone_vec(i)?;
+
+        self.check_zone_vec(j)?;
+
+        self.swap_zone_vectors(i, j);
+
+        return Ok(());
+    }
+
+    pub fn try_clone_zone_vec(&mut self, i: usize) -> Result<(), LayoutError> {
+        self.check_zone_vec(i)?;
+
+        self.clone_zone_vec(i);
+
+        return Ok(());
+    }
+
+    pub fn try_split(
+        &mut self,
+        i: usize,
+        j: usize,
+        direction: SplitDirection,
+    ) -> Result<(), LayoutError> {
+        self.check_zone(i, j)?;
+
+        check_split_position(i, j, &self.zones[i][j], &direction)?;
+
+        self.split(i, j, direction);
+
+        return Ok(());
+    }
+
+    pub fn try_merge_zones(&mut self, i: usize, j: usize, k: usize) -> Result<(), LayoutError> {
+        self.merged_zone(i, j, k)?;
+
+        self.merge_zones(i, j, k);
+
+        return Ok(());
+    }
+
+    pub fn try_swap_zones(&mut self, i: usize, j: usize, k: usize) -> Result<(), LayoutError> {
+        self.check_zone(i, j)?;
+
+        self.check_zone(i, k)?;
+
+        self.swap_zones(i, j, k);
+
+        return Ok(());
+    }
+
+    pub fn try_merge_and_split_zones(
+        &mut self,
+        i: usize,
+        j: usize,
+        k: usize,
+        direction: SplitDirection,
+    ) -> Result<(), LayoutError> {
+        let merged_zone = self.merged_zone(i, j, k)?;
+
+        check_split_position(i, std::cmp::min(j, k), &merged_zone, &direction)?;
+
+        self.merge_and_split_zones(i, j, k, direction);
+
+        return Ok(());
+    }
+
+    pub fn try_set_end_tiling_direction(
+        &mut self,
+        new_direction: Direction,
+    ) -> Result<(), LayoutError> {
+        match self.end_tiling_behaviour {
+            EndTilingBehaviour::Repeating { .. }
+            | EndTilingBehaviour::Grid { .. }
+            | EndTilingBehaviour::CenteredMaster { .. }
+            | EndTilingBehaviour::Monocle { .. } => {
+                return Err(LayoutError::EndTilingBehaviourMismatch)
+            }
+
+            _ => {
+                self.set_end_tiling_direction(new_direction);
+
+                return Ok(());
+            }
+        }
+    }
+
+    pub fn try_set_end_tiling_start_from(&mut self, val: usize) -> Result<(), LayoutError> {
+        match self.end_tiling_behaviour {
+            EndTilingBehaviour::Directional { .. } if val == 0 || val > self.manual_zones_until => {
+                return Err(LayoutError::StartFromOutOfRange {
+                    start_from: val,
+                    manual_zones_until: self.manual_zones_until,
+                });
+            }
+
+            EndTilingBehaviour::Directional { .. } => {
+                self.set_end_tiling_start_from(val);
+
+                return Ok(());
+            }
+
+            _ => return Err(LayoutError::EndTilingBehaviourMismatch),
+        }
+    }
+
+    pub fn try_set_end_tiling_ratio(&mut self, val: f64) -> Result<(), LayoutError> {
+        match self.end_tiling_behaviour {
+            EndTilingBehaviour::Spiral { .. }
+            | EndTilingBehaviour::Dwindle { .. }
+            | EndTilingBehaviour::MasterStack { .. }
+            | EndTilingBehaviour::CenteredMaster { .. } => {
+                if !(val > 0.0 && val < 1.0) {
+                    return Err(LayoutError::InvalidEndTilingRatio { ratio: val });
+                }
+
+                self.set_end_tiling_ratio(val);
+
+                return Ok(());
+            }
+
+            _ => return Err(LayoutError::EndTilingBehaviourMismatch),
+        }
+    }
+
+    pub fn try_set_max_zones(&mut self, val: Option<usize>) -> Result<(), LayoutError> {
+        if val == Some(0) {
+            return Err(LayoutError::NoMaxZones);
+        }
+
+        self.set_max_zones(val);
+
+        return Ok(());
+    }
+
+    pub fn try_set_max_windows(&mut self, val: Option<usize>) -> Result<(), LayoutError> {
+        if val == Some(0) {
+            return Err(LayoutError::NoMaxWindows);
+        }
+
+        self.set_max_windows(val);
+
+        return Ok(());
+    }
+
+    pub fn try_set_auto_split_bias(&mut self, val: f64) -> Result<(), LayoutError> {
+        if !(val > 0.0 && val.is_finite()) {
+            return Err(LayoutError::InvalidAutoSplitBias { bias: val });
+        }
+
+        self.set_auto_split_bias(val);
+
+        return Ok(());
+    }
+
+    pub fn try_set_master_count(&mut self, val: usize) -> Result<(), LayoutError> {
+        match self.end_tiling_behaviour {
+            EndTilingBehaviour::MasterStack { .. } => {
+                self.set_master_count(val);
+
+                return Ok(());
+            }
+
+            _ => return Err(LayoutError::EndTilingBehaviourMismatch),
+        }
+    }
+
+    pub fn try_set_grid_preference(&mut self, val: GridPreference) -> Result<(), LayoutError> {
+        match self.end_tiling_behaviour {
+            EndTilingBehaviour::Grid { .. } => {
+                self.set_grid_preference(val);
+
+                return Ok(());
+            }
+
+            _ => return Err(LayoutError::EndTilingBehaviourMismatch),
+        }
+    }
+
+    pub fn try_set_grid_fill(&mut self, val: GridFill) -> Result<(), LayoutError> {
+        match self.end_tiling_behaviour {
+            EndTilingBehaviour::Grid { .. } => {
+                self.set_grid_fill(val);
+
+                return Ok(());
+            }
+
+            _ => return Err(LayoutError::EndTilingBehaviourMismatch),
+        }
+    }
+
+    pub fn try_set_end_tiling_clockwise(&mut self, val: bool) -> Result<(), LayoutError> {
+        match self.end_tiling_behaviour {
+            EndTilingBehaviour::Spiral { .. } => {
+                self.set_end_tiling_clockwise(val);
+
+                return Ok(());
+            }
+
+            _ => return Err(LayoutError::EndTilingBehaviourMismatch),
+        }
+    }
+
+    pub fn try_add_repeating_split(
+        &mut self,
+        direction: Direction,
+        split_ratio: f64,
+        split_idx_offset: usize,
+        swap: bool,
+    ) -> Result<(), LayoutError> {
+        match &self.end_tiling_behaviour {
+            EndTilingBehaviour::Repeating {
+                splits,
+                zone_idx: _,
+            } => {
+                let j = splits.last().map_or(0, |split_vec| split_vec.len());
+
+                check_split_ratio(splits.len(), j, split_ratio)?;
+            }
+
+            _ => return Err(LayoutError::EndTilingBehaviourMismatch),
+        }
+
+        self.add_repeating_split(direction, split_ratio, split_idx_offset, swap);
+
+        return Ok(());
+    }
+
+    pub fn try_remove_repeating_split(&mut self, i: usize, j: usize) -> Result<(), LayoutError> {
+        self.repeating_split_mut(i, j)?;
+
+        self.remove_repeating_split(i, j);
+
+        return Ok(());
+    }
+
+    pub fn try_set_repeating_split_direction(
+        &mut self,
+        i: usize,
+        j: usize,
+        direction: Direction,
+    ) -> Result<(), LayoutError> {
+        self.repeating_split_mut(i, j)?;
+
+        self.set_repeating_split_direction(i, j, direction);
+
+        return Ok(());
+    }
+
+    pub fn try_set_repeating_split_ratio(
+        &mut self,
+        i: usize,
+        j: usize,
+        val: f64,
+    ) -> Result<(), LayoutError> {
+        check_split_ratio(i, j, val)?;
+
+        self.repeating_split_mut(i, j)?;
+
+        self.set_repeating_split_ratio(i, j, val);
+
+        return Ok(());
+    }
+
+    pub fn try_set_repeating_split_idx_offset(
+        &mut self,
+        i: usize,
+        j: usize,
+        val: usize,
+    ) -> Result<(), LayoutError> {
+        self.repeating_split_mut(i, j)?;
+
+        self.set_repeating_split_idx_offset(i, j, val);
+
+        return Ok(());
+    }
+
+    pub fn try_set_repeating_split_swap(
+        &mut self,
+        i: usize,
+        j: usize,
+        val: bool,
+    ) -> Result<(), LayoutError> {
+        self.repeating_split_mut(i, j)?;
+
+        self.set_repeating_split_swap(i, j, val);
+
+        return Ok(());
+    }
+}
+
+impl Layout {
+    fn check_variant(&self, variant_idx: usize) -> Result<(), LayoutError> {
+        if variant_idx < self.variants.len() {
+            return Ok(());
+        } else {
+            return Err(LayoutError::VariantOutOfRange {
+                variant_idx,
+                variants_len: self.variants.len(),
+            });
+        }
+    }
+
+    pub fn try_get_variant(&self, variant_idx: usize) -> Result<&Variant, LayoutError> {
+        self.check_variant(variant_idx)?;
+
+        return Ok(&self.variants[variant_idx]);
+    }
+
+    pub fn try_get_variant_mut(&mut self, variant_idx: usize) -> Result<&mut Variant, LayoutError> {
+        self.check_variant(variant_idx)?;
+
+        return Ok(&mut self.variants[variant_idx]);
+    }
+
+    pub fn try_clone_variant(&mut self, idx: usize) -> Result<(), LayoutError> {
+        self.check_variant(idx)?;
+
+        self.clone_variant(idx);
+
+        return Ok(());
+    }
+
+    pub fn try_insert_variant(&mut self, idx: usize, variant: Variant) -> Result<(), LayoutError> {
+        if idx > self.variants.len() {
+            return Err(LayoutError::VariantOutOfRange {
+                variant_idx: idx,
+                variants_len: self.variants.len(),
+            });
+        }
+
+        self.insert_variant(idx, variant);
+
+        return Ok(());
+    }
+
+    pub fn try_swap_variants(&mut self, i: usize, j: usize) -> Result<(), LayoutError> {
+        self.check_variant(i)?;
+
+        self.check_variant(j)?;
+
+        self.swap_variants(i, j);
+
+        return Ok(());
+    }
+
+    pub fn try_delete_variant(&mut self, idx: usize) -> Result<(), LayoutError> {
+        self.check_variant(idx)?;
+
+        if self.variants.len() == 1 {
+            return Err(LayoutError::LastVariant);
+        }
+
+        self.delete_variant(idx);
+
+        return Ok(());
+    }
+
+    pub fn try_set_default_variant_idx(&mut self, i: usize) -> Result<(), LayoutError> {
+        self.check_variant(i)?;
+
+        self.set_default_variant_idx(i);
+
+        return Ok(());
+    }
+}
+
+impl Variant {
+    pub fn resize_edge(
+        &mut self,
+        i: usize,
+        j: usize,
+        edge: Edge,
+        delta: i32,
+    ) -> Result<(), LayoutError> {
+        self.check_zone(i, j)?;
+
+        if delta == 0 {
+            return Ok(());
+        }
+
+        let zones = &self.zones[i];
+
+        let bounds = bounding_zone(zones).unwrap();
+
+        let zone = &zones[j];
+
+        let vertical_line = matches!(edge, Edge::Left | Edge::Right);
+
+        let (at, bounds_start, bounds_end, mut span) = match edge {
+            Edge::Left => (
+                zone.left,
+                bounds.left,
+                bounds.right,
+                (zone.top, zone.bottom),
+            ),
+
+            Edge::Right => (
+                zone.right,
+                bounds.left,
+                bounds.right,
+                (zone.top, zone.bottom),
+            ),
+
+            Edge::Top => (zone.top, bounds.top, bounds.bottom, (zone.left, zone.right)),
+
+            Edge::Bottom => (
+                zone.bottom,
+                bounds.top,
+                bounds.bottom,
+                (zone.left, zone.right),
+            ),
+        };
+
+        if at == bounds_start || at == bounds_end {
+            return Err(LayoutError::EdgeOnMonitorBoundary { i, j });
+        }
+
+        let extent = |zone: &Zone| {
+            if vertical_line {
+                (zone.left, zone.right, zone.top, zone.bottom)
+            } else {
+                (zone.top, zone.bottom, zone.left, zone.right)
+            }
+        };
+
+        let mut affected = vec![false; zones.len()];
+
+        loop {
+            let mut changed = false;
+
+            for (k, zone) in zones.iter().enumerate() {
+                let (start, end, span_start, span_end) = extent(zone);
+
+                if !affected[k]
+                    && (start == at || end == at)
+                    && span_start < span.1
+                    && span.0 < span_end
+                {
+                    affected[k] = true;
+
+                    span = (
+                        std::cmp::min(span.0, span_start),
+                        std::cmp::max(span.1, span_end),
+                    );
+
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        let new_at = at + delta;
+
+        for (k, zone) in zones.iter().enumerate() {
+            if !affected[k] {
+                continue;
+            }
+
+            let (start, end, _, _) = extent(zone);
+
+            let new_len = if start == at {
+                end - new_at
+            } else {
+                new_at - start
+            };
+
+            let min_len = if vertical_line {
+                self.min_size_at(k).width
+            } else {
+                self.min_size_at(k).height
+            };
+
+            if new_len < std::cmp::max(min_len, 1) {
+                return Err(LayoutError::ZoneTooSmall { i, j: k });
+            }
+        }
+
+        for (k, zone) in self.zones[i].iter_mut().enumerate() {
+            if !affected[k] {
+                continue;
+            }
+
+            if vertical_line {
+                if zone.left == at {
+                    zone.left = new_at;
+                } else {
+                    zone.right = new_at;
+                }
+            } else if zone.top == at {
+                zone.top = new_at;
+            } else {
+                zone.bottom = new_at;
+            }
+        }
+
+        if i < self.manual_zones_until {
+            self.detach_split_tree(i);
+
+            self.reset_generated_zones();
+        }
+
+        return Ok(());
+    }
+}
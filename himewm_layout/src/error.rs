@@ -0,0 +1,335 @@
+use crate::LAYOUT_VERSION;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LayoutError {
+    EmptyMonitorRect,
+    NoVariants,
+    DefaultVariantOutOfRange {
+        default_variant_idx: usize,
+        variants_len: usize,
+    },
+    InVariant {
+        variant_idx: usize,
+        error: Box<LayoutError>,
+    },
+    NoZones,
+    ManualZonesUntilOutOfRange {
+        manual_zones_until: usize,
+        zones_len: usize,
+    },
+    WrongZoneCount {
+        i: usize,
+        expected: usize,
+        found: usize,
+    },
+    EmptyZone {
+        i: usize,
+        j: usize,
+    },
+    ZoneOutsideMonitor {
+        i: usize,
+        j: usize,
+    },
+    OverlappingZones {
+        i: usize,
+        j: usize,
+        k: usize,
+    },
+    Gap {
+        i: usize,
+    },
+    StartFromOutOfRange {
+        start_from: usize,
+        manual_zones_until: usize,
+    },
+    EndZoneIdxOutOfRange {
+        zone_idx: usize,
+        zones_len: usize,
+    },
+    EmptyRepeatingSplits {
+        i: Option<usize>,
+    },
+    InvalidSplitRatio {
+        i: usize,
+        j: usize,
+        split_ratio: f64,
+    },
+    SplitIdxOffsetOutOfRange {
+        i: usize,
+        j: usize,
+        split_idx_offset: usize,
+    },
+    ZoneVecOutOfRange {
+        i: usize,
+        zones_len: usize,
+    },
+    ZoneOutOfRange {
+        i: usize,
+        j: usize,
+        zones_len: usize,
+    },
+    RepeatingSplitOutOfRange {
+        i: usize,
+        j: usize,
+    },
+    SplitOutsideZone {
+        i: usize,
+        j: usize,
+        at: i32,
+    },
+    ZonesNotAdjacent {
+        i: usize,
+        j: usize,
+        k: usize,
+    },
+    LastZoneVec,
+    EndTilingBehaviourMismatch,
+    VariantOutOfRange {
+        variant_idx: usize,
+        variants_len: usize,
+    },
+    LastVariant,
+    NoSplitTree {
+        i: usize,
+    },
+    SplitTreeCountMismatch {
+        trees_len: usize,
+        manual_zones_until: usize,
+    },
+    InSplitTree {
+        i: usize,
+        error: Box<LayoutError>,
+    },
+    SplitTreeNodeOutOfRange {
+        node: usize,
+    },
+    InvalidTreeRatio {
+        node: usize,
+        ratio: f64,
+    },
+    EdgeOnMonitorBoundary {
+        i: usize,
+        j: usize,
+    },
+    ZoneTooSmall {
+        i: usize,
+        j: usize,
+    },
+    InvalidEndTilingRatio {
+        ratio: f64,
+    },
+    NoMaxZones,
+    NoMaxWindows,
+    InvalidJson {
+        message: String,
+    },
+    UnsupportedVersion {
+        version: u64,
+    },
+    InvalidAutoSplitBias {
+        bias: f64,
+    },
+}
+
+impl std::fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LayoutError::EmptyMonitorRect => write!(f, "monitor rect has no area"),
+
+            LayoutError::NoVariants => write!(f, "layout has no variants"),
+
+            LayoutError::DefaultVariantOutOfRange {
+                default_variant_idx,
+                variants_len,
+            } => write!(
+                f,
+                "default variant {} is out of range for {} variants",
+                default_variant_idx, variants_len
+            ),
+
+            LayoutError::InVariant { variant_idx, error } => {
+                write!(f, "variant {}: {}", variant_idx, error)
+            }
+
+            LayoutError::NoZones => write!(f, "variant has no zones"),
+
+            LayoutError::ManualZonesUntilOutOfRange {
+                manual_zones_until,
+                zones_len,
+            } => write!(
+                f,
+                "manual_zones_until {} is out of range for {} zone vectors",
+                manual_zones_until, zones_len
+            ),
+
+            LayoutError::WrongZoneCount { i, expected, found } => write!(
+                f,
+                "zone vector {} has {} zones, expected {}",
+                i, found, expected
+            ),
+
+            LayoutError::EmptyZone { i, j } => {
+                write!(f, "zone {} of zone vector {} is empty", j, i)
+            }
+
+            LayoutError::ZoneOutsideMonitor { i, j } => write!(
+                f,
+                "zone {} of zone vector {} lies outside the monitor rect",
+                j, i
+            ),
+
+            LayoutError::OverlappingZones { i, j, k } => {
+                write!(f, "zones {} and {} of zone vector {} overlap", j, k, i)
+            }
+
+            LayoutError::Gap { i } => {
+                write!(f, "zone vector {} does not cover the whole monitor rect", i)
+            }
+
+            LayoutError::StartFromOutOfRange {
+                start_from,
+                manual_zones_until,
+            } => write!(
+                f,
+                "start_from {} is out of range for {} manual zone vectors",
+                start_from, manual_zones_until
+            ),
+
+            LayoutError::EndZoneIdxOutOfRange {
+                zone_idx,
+                zones_len,
+            } => write!(
+                f,
+                "end tiling zone {} is out of range for {} zones",
+                zone_idx, zones_len
+            ),
+
+            LayoutError::EmptyRepeatingSplits { i: None } => {
+                write!(f, "repeating end tiling has no splits")
+            }
+
+            LayoutError::EmptyRepeatingSplits { i: Some(i) } => {
+                write!(f, "repeating split set {} is empty", i)
+            }
+
+            LayoutError::InvalidSplitRatio { i, j, split_ratio } => write!(
+                f,
+                "split {} of repeating split set {} has invalid ratio {}",
+                j, i, split_ratio
+            ),
+
+            LayoutError::SplitIdxOffsetOutOfRange {
+                i,
+                j,
+                split_idx_offset,
+            } => write!(
+                f,
+                "split {} of repeating split set {} has out of range offset {}",
+                j, i, split_idx_offset
+            ),
+
+            LayoutError::ZoneVecOutOfRange { i, zones_len } => write!(
+                f,
+                "zone vector {} is out of range for {} zone vectors",
+                i, zones_len
+            ),
+
+            LayoutError::ZoneOutOfRange { i, j, zones_len } => write!(
+                f,
+                "zone {} is out of range for {} zones in zone vector {}",
+                j, zones_len, i
+            ),
+
+            LayoutError::RepeatingSplitOutOfRange { i, j } => {
+                write!(f, "repeating split {} of split set {} does not exist", j, i)
+            }
+
+            LayoutError::SplitOutsideZone { i, j, at } => write!(
+                f,
+                "split at {} lies outside zone {} of zone vector {}",
+                at, j, i
+            ),
+
+            LayoutError::ZonesNotAdjacent { i, j, k } => write!(
+                f,
+                "zones {} and {} of zone vector {} cannot be merged",
+                j, k, i
+            ),
+
+            LayoutError::LastZoneVec => write!(f, "the last manual zone vector cannot be deleted"),
+
+            LayoutError::EndTilingBehaviourMismatch => write!(
+                f,
+                "operation does not apply to the current end tiling behaviour"
+            ),
+
+            LayoutError::VariantOutOfRange {
+                variant_idx,
+                variants_len,
+            } => write!(
+                f,
+                "variant {} is out of range for {} variants",
+                variant_idx, variants_len
+            ),
+
+            LayoutError::LastVariant => write!(f, "the last variant cannot be deleted"),
+
+            LayoutError::NoSplitTree { i } => write!(f, "zone vector {} has no split tree", i),
+
+            LayoutError::SplitTreeCountMismatch {
+                trees_len,
+                manual_zones_until,
+            } => write!(
+                f,
+                "{} split trees do not match {} manual zone vectors",
+                trees_len, manual_zones_until
+            ),
+
+            LayoutError::InSplitTree { i, error } => write!(f, "split tree {}: {}", i, error),
+
+            LayoutError::SplitTreeNodeOutOfRange { node } => {
+                write!(f, "node {} is not a split node", node)
+            }
+
+            LayoutError::InvalidTreeRatio { node, ratio } => {
+                write!(f, "node {} has invalid ratio {}", node, ratio)
+            }
+
+            LayoutError::EdgeOnMonitorBoundary { i, j } => write!(
+                f,
+                "edge of zone {} of zone vector {} lies on the monitor boundary",
+                j, i
+            ),
+
+            LayoutError::ZoneTooSmall { i, j } => write!(
+                f,
+                "zone {} of zone vector {} would become smaller than its minimum size",
+                j, i
+            ),
+
+            LayoutError::InvalidEndTilingRatio { ratio } => {
+                write!(f, "end tiling ratio {} is not between 0 and 1", ratio)
+            }
+
+            LayoutError::NoMaxZones => write!(f, "max zones must be at least 1"),
+
+            LayoutError::NoMaxWindows => write!(f, "max windows must be at least 1"),
+
+            LayoutError::InvalidJson { message } => write!(f, "invalid layout json: {}", message),
+
+            LayoutError::UnsupportedVersion { version } => {
+                write!(
+                    f,
+                    "layout version {} is newer than {}",
+                    version, LAYOUT_VERSION
+                )
+            }
+
+            LayoutError::InvalidAutoSplitBias { bias } => {
+                write!(f, "auto split bias {} is not a positive number", bias)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LayoutError {}
@@ -0,0 +1,896 @@
+use crate::*;
+
+#[derive(Clone, Debug)]
+pub enum LayoutEdit {
+    SetMonitorRect {
+        zone: Zone,
+    },
+    NewVariant,
+    CloneVariant {
+        variant_idx: usize,
+    },
+    SwapVariants {
+        i: usize,
+        j: usize,
+    },
+    DeleteVariant {
+        variant_idx: usize,
+    },
+    InsertVariant {
+        variant_idx: usize,
+        variant: Box<Variant>,
+    },
+    SetDefaultVariantIdx {
+        variant_idx: usize,
+    },
+    SetLayoutMetadata {
+        metadata: Metadata,
+    },
+    SetVariantMetadata {
+        variant_idx: usize,
+        metadata: Metadata,
+    },
+    NewZoneVec {
+        variant_idx: usize,
+    },
+    CloneZoneVec {
+        variant_idx: usize,
+        i: usize,
+    },
+    DeleteZones {
+        variant_idx: usize,
+        i: usize,
+    },
+    InsertZoneVec {
+        variant_idx: usize,
+        i: usize,
+        zones: Vec<Zone>,
+        split_tree: Option<SplitTree>,
+    },
+    SetZoneVec {
+        variant_idx: usize,
+        i: usize,
+        zones: Vec<Zone>,
+        split_tree: Option<SplitTree>,
+    },
+    SwapZoneVectors {
+        variant_idx: usize,
+        i: usize,
+        j: usize,
+    },
+    Split {
+        variant_idx: usize,
+        i: usize,
+        j: usize,
+        direction: SplitDirection,
+    },
+    MergeZones {
+        variant_idx: usize,
+        i: usize,
+        j: usize,
+        k: usize,
+    },
+    SwapZones {
+        variant_idx: usize,
+        i: usize,
+        j: usize,
+        k: usize,
+    },
+    MergeAndSplitZones {
+        variant_idx: usize,
+        i: usize,
+        j: usize,
+        k: usize,
+        direction: SplitDirection,
+    },
+    SetEndTilingBehaviour {
+        variant_idx: usize,
+        behaviour: EndTilingBehaviour,
+    },
+    SetEndTilingDirection {
+        variant_idx: usize,
+        direction: Direction,
+    },
+    SetEndTilingStartFrom {
+        variant_idx: usize,
+        start_from: usize,
+    },
+    SetEndTilingRatio {
+        variant_idx: usize,
+        ratio: f64,
+    },
+    SetEndTilingClockwise {
+        variant_idx: usize,
+        clockwise: bool,
+    },
+    SetMaxZones {
+        variant_idx: usize,
+        max_zones: Option<usize>,
+    },
+    SetMaxWindows {
+        variant_idx: usize,
+        max_windows: Option<usize>,
+    },
+    SetOverflowPolicy {
+        variant_idx: usize,
+        policy: OverflowPolicy,
+    },
+    SetAutoSplitBias {
+        variant_idx: usize,
+        bias: f64,
+    },
+    SetMinSize {
+        variant_idx: usize,
+        min_size: MinSize,
+    },
+    SetZoneMinSize {
+        variant_idx: usize,
+        j: usize,
+        min_size: Option<MinSize>,
+    },
+    SetMinSizeOverflow {
+        variant_idx: usize,
+        overflow: MinSizeOverflow,
+    },
+    SetMasterCount {
+        variant_idx: usize,
+        master_count: usize,
+    },
+    SetGridPreference {
+        variant_idx: usize,
+        preference: GridPreference,
+    },
+    SetGridFill {
+        variant_idx: usize,
+        fill: GridFill,
+    },
+    AddRepeatingSplit {
+        variant_idx: usize,
+        split: RepeatingSplit,
+    },
+    RemoveRepeatingSplit {
+        variant_idx: usize,
+        i: usize,
+        j: usize,
+    },
+    SetRepeatingSplitDirection {
+        variant_idx: usize,
+        i: usize,
+        j: usize,
+        direction: Direction,
+    },
+    SetRepeatingSplitRatio {
+        variant_idx: usize,
+        i: usize,
+        j: usize,
+        split_ratio: f64,
+    },
+    SetRepeatingSplitIdxOffset {
+        variant_idx: usize,
+        i: usize,
+        j: usize,
+        split_idx_offset: usize,
+    },
+    SetRepeatingSplitSwap {
+        variant_idx: usize,
+        i: usize,
+        j: usize,
+        swap: bool,
+    },
+    SetSplitRatio {
+        variant_idx: usize,
+        i: usize,
+        node: usize,
+        ratio: f64,
+    },
+    ResizeEdge {
+        variant_idx: usize,
+        i: usize,
+        j: usize,
+        edge: Edge,
+        delta: i32,
+    },
+}
+
+impl LayoutEdit {
+    pub fn apply(&self, layout: &mut Layout) -> Result<Vec<LayoutEdit>, LayoutError> {
+        let inverse = self.inverse(layout);
+
+        self.apply_without_inverse(layout)?;
+
+        return Ok(inverse.unwrap_or_default());
+    }
+
+    fn apply_without_inverse(&self, layout: &mut Layout) -> Result<(), LayoutError> {
+        match self {
+            LayoutEdit::SetMonitorRect { zone } => {
+                layout.set_monitor_rect(zone.clone());
+
+                return Ok(());
+            }
+
+            LayoutEdit::NewVariant => {
+                layout.new_variant();
+
+                return Ok(());
+            }
+
+            LayoutEdit::CloneVariant { variant_idx } => layout.try_clone_variant(*variant_idx),
+
+            LayoutEdit::SwapVariants { i, j } => layout.try_swap_variants(*i, *j),
+
+            LayoutEdit::DeleteVariant { variant_idx } => layout.try_delete_variant(*variant_idx),
+
+            LayoutEdit::InsertVariant {
+                variant_idx,
+                variant,
+            } => layout.try_insert_variant(*variant_idx, *variant.clone()),
+
+            LayoutEdit::SetDefaultVariantIdx { variant_idx } => {
+                layout.try_set_default_variant_idx(*variant_idx)
+            }
+
+            LayoutEdit::SetLayoutMetadata { metadata } => {
+                layout.set_metadata(metadata.clone());
+
+                return Ok(());
+            }
+
+            LayoutEdit::SetVariantMetadata {
+                variant_idx,
+                metadata,
+            } => {
+                layout
+                    .try_get_variant_mut(*variant_idx)?
+                    .set_metadata(metadata.clone());
+
+                return Ok(());
+            }
+
+            LayoutEdit::NewZoneVec { variant_idx } => {
+                let Zone { right, bottom, .. } = layout.get_monitor_rect().to_owned();
+
+                layout
+                    .try_get_variant_mut(*variant_idx)?
+                    .new_zone_vec(right, bottom);
+
+                return Ok(());
+            }
+
+            LayoutEdit::CloneZoneVec { variant_idx, i } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_clone_zone_vec(*i),
+
+            LayoutEdit::DeleteZones { variant_idx, i } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_delete_zones(*i),
+
+            LayoutEdit::InsertZoneVec {
+                variant_idx,
+                i,
+                zones,
+                split_tree,
+            } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_insert_zone_vec(*i, zones.clone(), split_tree.clone()),
+
+            LayoutEdit::SetZoneVec {
+                variant_idx,
+                i,
+                zones,
+                split_tree,
+            } => layout.try_get_variant_mut(*variant_idx)?.try_set_zone_vec(
+                *i,
+                zones.clone(),
+                split_tree.clone(),
+            ),
+
+            LayoutEdit::SwapZoneVectors { variant_idx, i, j } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_swap_zone_vectors(*i, *j),
+
+            LayoutEdit::Split {
+                variant_idx,
+                i,
+                j,
+                direction,
+            } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_split(*i, *j, direction.clone()),
+
+            LayoutEdit::MergeZones {
+                variant_idx,
+                i,
+                j,
+                k,
+            } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_merge_zones(*i, *j, *k),
+
+            LayoutEdit::SwapZones {
+                variant_idx,
+                i,
+                j,
+                k,
+            } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_swap_zones(*i, *j, *k),
+
+            LayoutEdit::MergeAndSplitZones {
+                variant_idx,
+                i,
+                j,
+                k,
+                direction,
+            } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_merge_and_split_zones(*i, *j, *k, direction.clone()),
+
+            LayoutEdit::SetEndTilingBehaviour {
+                variant_idx,
+                behaviour,
+            } => {
+                layout
+                    .try_get_variant_mut(*variant_idx)?
+                    .set_end_tiling_behaviour(behaviour.clone());
+
+                return Ok(());
+            }
+
+            LayoutEdit::SetEndTilingDirection {
+                variant_idx,
+                direction,
+            } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_set_end_tiling_direction(direction.clone()),
+
+            LayoutEdit::SetEndTilingStartFrom {
+                variant_idx,
+                start_from,
+            } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_set_end_tiling_start_from(*start_from),
+
+            LayoutEdit::SetEndTilingRatio { variant_idx, ratio } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_set_end_tiling_ratio(*ratio),
+
+            LayoutEdit::SetEndTilingClockwise {
+                variant_idx,
+                clockwise,
+            } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_set_end_tiling_clockwise(*clockwise),
+
+            LayoutEdit::SetMaxZones {
+                variant_idx,
+                max_zones,
+            } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_set_max_zones(*max_zones),
+
+            LayoutEdit::SetMaxWindows {
+                variant_idx,
+                max_windows,
+            } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_set_max_windows(*max_windows),
+
+            LayoutEdit::SetOverflowPolicy {
+                variant_idx,
+                policy,
+            } => {
+                layout
+                    .try_get_variant_mut(*variant_idx)?
+                    .set_overflow_policy(policy.clone());
+
+                return Ok(());
+            }
+
+            LayoutEdit::SetAutoSplitBias { variant_idx, bias } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_set_auto_split_bias(*bias),
+
+            LayoutEdit::SetMinSize {
+                variant_idx,
+                min_size,
+            } => {
+                layout
+                    .try_get_variant_mut(*variant_idx)?
+                    .set_min_size(min_size.clone());
+
+                return Ok(());
+            }
+
+            LayoutEdit::SetZoneMinSize {
+                variant_idx,
+                j,
+                min_size,
+            } => {
+                layout
+                    .try_get_variant_mut(*variant_idx)?
+                    .set_zone_min_size(*j, min_size.clone());
+
+                return Ok(());
+            }
+
+            LayoutEdit::SetMinSizeOverflow {
+                variant_idx,
+                overflow,
+            } => {
+                layout
+                    .try_get_variant_mut(*variant_idx)?
+                    .set_min_size_overflow(overflow.clone());
+
+                return Ok(());
+            }
+
+            LayoutEdit::SetMasterCount {
+                variant_idx,
+                master_count,
+            } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_set_master_count(*master_count),
+
+            LayoutEdit::SetGridPreference {
+                variant_idx,
+                preference,
+            } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_set_grid_preference(preference.clone()),
+
+            LayoutEdit::SetGridFill { variant_idx, fill } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_set_grid_fill(fill.clone()),
+
+            LayoutEdit::AddRepeatingSplit { variant_idx, split } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_add_repeating_split(
+                    split.direction.clone(),
+                    split.split_ratio,
+                    split.split_idx_offset,
+                    split.swap,
+                ),
+
+            LayoutEdit::RemoveRepeatingSplit { variant_idx, i, j } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_remove_repeating_split(*i, *j),
+
+            LayoutEdit::SetRepeatingSplitDirection {
+                variant_idx,
+                i,
+                j,
+                direction,
+            } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_set_repeating_split_direction(*i, *j, direction.clone()),
+
+            LayoutEdit::SetRepeatingSplitRatio {
+                variant_idx,
+                i,
+                j,
+                split_ratio,
+            } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_set_repeating_split_ratio(*i, *j, *split_ratio),
+
+            LayoutEdit::SetRepeatingSplitIdxOffset {
+                variant_idx,
+                i,
+                j,
+                split_idx_offset,
+            } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_set_repeating_split_idx_offset(*i, *j, *split_idx_offset),
+
+            LayoutEdit::SetRepeatingSplitSwap {
+                variant_idx,
+                i,
+                j,
+                swap,
+            } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_set_repeating_split_swap(*i, *j, *swap),
+
+            LayoutEdit::SetSplitRatio {
+                variant_idx,
+                i,
+                node,
+                ratio,
+            } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .try_set_split_ratio(*i, *node, *ratio),
+
+            LayoutEdit::ResizeEdge {
+                variant_idx,
+                i,
+                j,
+                edge,
+                delta,
+            } => layout
+                .try_get_variant_mut(*variant_idx)?
+                .resize_edge(*i, *j, *edge, *delta),
+        }
+    }
+
+    fn inverse(&self, layout: &Layout) -> Option<Vec<LayoutEdit>> {
+        let variant = |variant_idx: &usize| layout.try_get_variant(*variant_idx).ok();
+
+        let default_variant_idx = LayoutEdit::SetDefaultVariantIdx {
+            variant_idx: layout.default_variant_idx(),
+        };
+
+        let ret = match self {
+            LayoutEdit::SetMonitorRect { .. } => vec![LayoutEdit::SetMonitorRect {
+                zone: layout.get_monitor_rect().clone(),
+            }],
+
+            LayoutEdit::NewVariant | LayoutEdit::CloneVariant { .. } => {
+                vec![LayoutEdit::DeleteVariant {
+                    variant_idx: layout.variants_len(),
+                }]
+            }
+
+            LayoutEdit::SwapVariants { i, j } => vec![LayoutEdit::SwapVariants { i: *i, j: *j }],
+
+            LayoutEdit::DeleteVariant { variant_idx } => vec![
+                LayoutEdit::InsertVariant {
+                    variant_idx: *variant_idx,
+                    variant: Box::new(variant(variant_idx)?.clone()),
+                },
+                default_variant_idx,
+            ],
+
+            LayoutEdit::InsertVariant { variant_idx, .. } => vec![
+                LayoutEdit::DeleteVariant {
+                    variant_idx: *variant_idx,
+                },
+                default_variant_idx,
+            ],
+
+            LayoutEdit::SetDefaultVariantIdx { .. } => vec![default_variant_idx],
+
+            LayoutEdit::SetLayoutMetadata { .. } => vec![LayoutEdit::SetLayoutMetadata {
+                metadata: layout.get_metadata().clone(),
+            }],
+
+            LayoutEdit::SetVariantMetadata { variant_idx, .. } => {
+                vec![LayoutEdit::SetVariantMetadata {
+                    variant_idx: *variant_idx,
+                    metadata: variant(variant_idx)?.get_metadata().clone(),
+                }]
+            }
+
+            LayoutEdit::NewZoneVec { variant_idx }
+            | LayoutEdit::CloneZoneVec { variant_idx, .. } => {
+                vec![LayoutEdit::DeleteZones {
+                    variant_idx: *variant_idx,
+                    i: variant(variant_idx)?.get_zones().len(),
+                }]
+            }
+
+            LayoutEdit::DeleteZones { variant_idx, i } => vec![LayoutEdit::InsertZoneVec {
+                variant_idx: *variant_idx,
+                i: *i,
+                zones: variant(variant_idx)?.get_zones().get(*i)?.clone(),
+                split_tree: variant(variant_idx)?.get_split_tree(*i).cloned(),
+            }],
+
+            LayoutEdit::InsertZoneVec { variant_idx, i, .. } => vec![LayoutEdit::DeleteZones {
+                variant_idx: *variant_idx,
+                i: *i,
+            }],
+
+            LayoutEdit::SwapZoneVectors { variant_idx, i, j } => {
+                vec![LayoutEdit::SwapZoneVectors {
+                    variant_idx: *variant_idx,
+                    i: *i,
+                    j: *j,
+                }]
+            }
+
+            LayoutEdit::SetZoneVec { variant_idx, i, .. }
+            | LayoutEdit::MergeZones { variant_idx, i, .. }
+            | LayoutEdit::SwapZones { variant_idx, i, .. }
+            | LayoutEdit::ResizeEdge { variant_idx, i, .. } => {
+                vec![saved_zone_vec(*variant_idx, variant(variant_idx)?, *i)?]
+            }
+
+            LayoutEdit::Split { variant_idx, i, .. }
+            | LayoutEdit::MergeAndSplitZones { variant_idx, i, .. } => vec![
+                saved_zone_vec(*variant_idx, variant(variant_idx)?, *i)?,
+                saved_end_tiling_behaviour(*variant_idx, variant(variant_idx)?),
+            ],
+
+            LayoutEdit::SetEndTilingBehaviour { variant_idx, .. }
+            | LayoutEdit::SetEndTilingDirection { variant_idx, .. }
+            | LayoutEdit::SetEndTilingStartFrom { variant_idx, .. }
+            | LayoutEdit::SetEndTilingRatio { variant_idx, .. }
+            | LayoutEdit::SetEndTilingClockwise { variant_idx, .. }
+            | LayoutEdit::SetMasterCount { variant_idx, .. }
+            | LayoutEdit::SetGridPreference { variant_idx, .. }
+            | LayoutEdit::SetGridFill { variant_idx, .. }
+            | LayoutEdit::AddRepeatingSplit { variant_idx, .. }
+            | LayoutEdit::RemoveRepeatingSplit { variant_idx, .. }
+            | LayoutEdit::SetRepeatingSplitDirection { variant_idx, .. }
+            | LayoutEdit::SetRepeatingSplitRatio { variant_idx, .. }
+            | LayoutEdit::SetRepeatingSplitIdxOffset { variant_idx, .. }
+            | LayoutEdit::SetRepeatingSplitSwap { variant_idx, .. } => {
+                vec![saved_end_tiling_behaviour(
+                    *variant_idx,
+                    variant(variant_idx)?,
+                )]
+            }
+
+            LayoutEdit::SetMaxZones { variant_idx, .. } => vec![LayoutEdit::SetMaxZones {
+                variant_idx: *variant_idx,
+                max_zones: variant(variant_idx)?.get_max_zones(),
+            }],
+
+            LayoutEdit::SetMaxWindows { variant_idx, .. } => vec![LayoutEdit::SetMaxWindows {
+                variant_idx: *variant_idx,
+                max_windows: variant(variant_idx)?.get_max_windows(),
+            }],
+
+            LayoutEdit::SetOverflowPolicy { variant_idx, .. } => {
+                vec![LayoutEdit::SetOverflowPolicy {
+                    variant_idx: *variant_idx,
+                    policy: variant(variant_idx)?.get_overflow_policy().clone(),
+                }]
+            }
+
+            LayoutEdit::SetAutoSplitBias { variant_idx, .. } => {
+                vec![LayoutEdit::SetAutoSplitBias {
+                    variant_idx: *variant_idx,
+                    bias: variant(variant_idx)?.get_auto_split_bias(),
+                }]
+            }
+
+            LayoutEdit::SetMinSize { variant_idx, .. } => vec![LayoutEdit::SetMinSize {
+                variant_idx: *variant_idx,
+                min_size: variant(variant_idx)?.get_min_size().clone(),
+            }],
+
+            LayoutEdit::SetZoneMinSize { variant_idx, j, .. } => {
+                vec![LayoutEdit::SetZoneMinSize {
+                    variant_idx: *variant_idx,
+                    j: *j,
+                    min_size: variant(variant_idx)?.get_zone_min_sizes().get(j).cloned(),
+                }]
+            }
+
+            LayoutEdit::SetMinSizeOverflow { variant_idx, .. } => {
+                vec![LayoutEdit::SetMinSizeOverflow {
+                    variant_idx: *variant_idx,
+                    overflow: variant(variant_idx)?.get_min_size_overflow().clone(),
+                }]
+            }
+
+            LayoutEdit::SetSplitRatio {
+                variant_idx,
+                i,
+                node,
+                ..
+            } => match variant(variant_idx)?.get_split_tree(*i)?.get_node(*node)? {
+                SplitTree::Split { ratio, .. } => vec![LayoutEdit::SetSplitRatio {
+                    variant_idx: *variant_idx,
+                    i: *i,
+                    node: *node,
+                    ratio: *ratio,
+                }],
+
+                SplitTree::Leaf => return None,
+            },
+        };
+
+        return Some(ret);
+    }
+}
+
+fn saved_zone_vec(variant_idx: usize, variant: &Variant, i: usize) -> Option<LayoutEdit> {
+    return Some(LayoutEdit::SetZoneVec {
+        variant_idx,
+        i,
+        zones: variant.get_zones().get(i)?.clone(),
+        split_tree: variant.get_split_tree(i).cloned(),
+    });
+}
+
+fn saved_end_tiling_behaviour(variant_idx: usize, variant: &Variant) -> LayoutEdit {
+    LayoutEdit::SetEndTilingBehaviour {
+        variant_idx,
+        behaviour: variant.end_tiling_behaviour.clone(),
+    }
+}
+
+fn apply_all(layout: &mut Layout, edits: &[LayoutEdit]) -> Result<Vec<LayoutEdit>, LayoutError> {
+    let mut inverses = Vec::new();
+
+    for edit in edits.iter() {
+        match edit.apply(layout) {
+            Ok(inverse) => {
+                inverses.splice(0..0, inverse);
+            }
+
+            Err(error) => {
+                let _ = apply_all(layout, &inverses);
+
+                return Err(error);
+            }
+        }
+    }
+
+    return Ok(inverses);
+}
+
+#[derive(Clone, Debug)]
+struct Transaction {
+    edits: Vec<LayoutEdit>,
+    inverses: Vec<LayoutEdit>,
+}
+
+#[derive(Clone, Debug)]
+pub struct LayoutEditor {
+    layout: Layout,
+    undo_stack: Vec<Transaction>,
+    redo_stack: Vec<Transaction>,
+    group: Option<Transaction>,
+    group_depth: usize,
+}
+
+impl LayoutEditor {
+    pub fn new(layout: Layout) -> Self {
+        LayoutEditor {
+            layout,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            group: None,
+            group_depth: 0,
+        }
+    }
+
+    pub fn get_layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    pub fn into_layout(self) -> Layout {
+        self.layout
+    }
+
+    pub fn apply(&mut self, edit: LayoutEdit) -> Result<(), LayoutError> {
+        let inverse = match edit.apply(&mut self.layout) {
+            Ok(val) => val,
+
+            Err(error) => {
+                self.cancel_group();
+
+                return Err(error);
+            }
+        };
+
+        match &mut self.group {
+            Some(group) => {
+                group.edits.push(edit);
+
+                group.inverses.splice(0..0, inverse);
+            }
+
+            None => {
+                self.undo_stack.push(Transaction {
+                    edits: vec![edit],
+                    inverses: inverse,
+                });
+
+                self.redo_stack.clear();
+            }
+        }
+
+        return Ok(());
+    }
+
+    pub fn begin_group(&mut self) {
+        if self.group.is_none() {
+            self.group = Some(Transaction {
+                edits: Vec::new(),
+                inverses: Vec::new(),
+            });
+        }
+
+        self.group_depth += 1;
+    }
+
+    pub fn end_group(&mut self) {
+        if self.group_depth == 0 {
+            return;
+        }
+
+        self.group_depth -= 1;
+
+        if self.group_depth == 0 {
+            self.commit_group();
+        }
+    }
+
+    fn commit_group(&mut self) {
+        if let Some(group) = self.group.take() {
+            if !group.edits.is_empty() {
+                self.undo_stack.push(group);
+
+                self.redo_stack.clear();
+            }
+        }
+    }
+
+    pub fn cancel_group(&mut self) {
+        if let Some(group) = self.group.take() {
+            let _ = apply_all(&mut self.layout, &group.inverses);
+        }
+
+        self.group_depth = 0;
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    pub fn undo(&mut self) -> bool {
+        self.group_depth = 0;
+
+        self.commit_group();
+
+        let transaction = match self.undo_stack.pop() {
+            Some(val) => val,
+
+            None => return false,
+        };
+
+        if apply_all(&mut self.layout, &transaction.inverses).is_err() {
+            self.undo_stack.push(transaction);
+
+            return false;
+        }
+
+        self.redo_stack.push(transaction);
+
+        return true;
+    }
+
+    pub fn redo(&mut self) -> bool {
+        if self.group.is_some() {
+            return false;
+        }
+
+        let transaction = match self.redo_stack.pop() {
+            Some(val) => val,
+
+            None => return false,
+        };
+
+        match apply_all(&mut self.layout, &transaction.edits) {
+            Ok(inverses) => {
+                self.undo_stack.push(Transaction {
+                    edits: transaction.edits,
+                    inverses,
+                });
+
+                return true;
+            }
+
+            Err(_) => {
+                self.redo_stack.push(transaction);
+
+                return false;
+            }
+        }
+    }
+
+    pub fn clear_history(&mut self) {
+        self.undo_stack.clear();
+
+        self.redo_stack.clear();
+    }
+}
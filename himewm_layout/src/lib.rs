@@ -1,11 +1,69 @@
+mod cells;
+
+mod dsl;
+
+mod edit;
+
+mod error;
+
+mod history;
+
+mod metadata;
+
+mod migrate;
+
+mod min_size;
+
+mod overflow;
+
+mod presets;
+
+mod relative;
+
+mod render;
+
+mod transform;
+
+mod tree;
+
+mod validate;
+
+pub use dsl::*;
+
+pub use error::*;
+
+pub use history::*;
+
+pub use metadata::*;
+
+pub use migrate::*;
+
+pub use min_size::*;
+
+pub use overflow::*;
+
+pub use presets::*;
+
+pub use relative::*;
+
+pub use transform::*;
+
+pub use tree::*;
+
+use cells::*;
+
 use serde::{Deserialize, Serialize};
 
-use windows::Win32::Foundation::*;
+use std::collections::BTreeMap;
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg(feature = "win32")]
+use windows::Win32::Foundation::RECT;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum Direction {
     Horizontal,
     Vertical,
+    Auto,
 }
 
 impl Direction {
@@ -13,8 +71,47 @@ impl Direction {
         match self {
             Direction::Horizontal => Direction::Vertical,
             Direction::Vertical => Direction::Horizontal,
+            Direction::Auto => Direction::Auto,
         }
     }
+
+    pub fn resolve(&self, zone: &Zone, auto_split_bias: f64) -> Self {
+        match self {
+            Direction::Auto => match zone.w() as f64 >= zone.h() as f64 * auto_split_bias {
+                true => Direction::Horizontal,
+
+                false => Direction::Vertical,
+            },
+
+            _ => self.clone(),
+        }
+    }
+}
+
+pub const DEFAULT_AUTO_SPLIT_BIAS: f64 = 1.0;
+
+fn default_auto_split_bias() -> f64 {
+    DEFAULT_AUTO_SPLIT_BIAS
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum Edge {
+    Left,
+    Top,
+    Right,
+    Bottom,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum GridPreference {
+    Rows,
+    Columns,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum GridFill {
+    Stretch,
+    ExpandLast,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -36,6 +133,42 @@ pub enum EndTilingBehaviour {
         splits: Vec<Vec<RepeatingSplit>>,
         zone_idx: usize,
     },
+
+    Spiral {
+        ratio: f64,
+        clockwise: bool,
+        start_direction: Direction,
+        zone_idx: usize,
+    },
+
+    Dwindle {
+        ratio: f64,
+        start_direction: Direction,
+        zone_idx: usize,
+    },
+
+    Grid {
+        preference: GridPreference,
+        fill: GridFill,
+        zone_idx: usize,
+    },
+
+    MasterStack {
+        master_count: usize,
+        master_ratio: f64,
+        stack_direction: Direction,
+        master_side: Edge,
+        zone_idx: usize,
+    },
+
+    CenteredMaster {
+        center_ratio: f64,
+        zone_idx: usize,
+    },
+
+    Monocle {
+        zone_idx: usize,
+    },
 }
 
 impl EndTilingBehaviour {
@@ -54,6 +187,88 @@ impl EndTilingBehaviour {
             zone_idx: 0,
         }
     }
+
+    pub fn default_spiral() -> Self {
+        EndTilingBehaviour::Spiral {
+            ratio: 0.5,
+            clockwise: true,
+            start_direction: Direction::Horizontal,
+            zone_idx: 0,
+        }
+    }
+
+    pub fn default_dwindle() -> Self {
+        EndTilingBehaviour::Dwindle {
+            ratio: 0.5,
+            start_direction: Direction::Horizontal,
+            zone_idx: 0,
+        }
+    }
+
+    pub fn default_grid() -> Self {
+        EndTilingBehaviour::Grid {
+            preference: GridPreference::Columns,
+            fill: GridFill::Stretch,
+            zone_idx: 0,
+        }
+    }
+
+    pub fn default_master_stack() -> Self {
+        EndTilingBehaviour::MasterStack {
+            master_count: 1,
+            master_ratio: 0.5,
+            stack_direction: Direction::Vertical,
+            master_side: Edge::Left,
+            zone_idx: 0,
+        }
+    }
+
+    pub fn default_centered_master() -> Self {
+        EndTilingBehaviour::CenteredMaster {
+            center_ratio: 0.5,
+            zone_idx: 0,
+        }
+    }
+
+    pub fn default_monocle() -> Self {
+        EndTilingBehaviour::Monocle { zone_idx: 0 }
+    }
+
+    pub fn with_other_direction(&self) -> Option<Self> {
+        let mut ret = self.clone();
+
+        match &mut ret {
+            EndTilingBehaviour::Directional { direction, .. } => {
+                *direction = direction.other();
+            }
+
+            EndTilingBehaviour::Spiral {
+                start_direction, ..
+            }
+            | EndTilingBehaviour::Dwindle {
+                start_direction, ..
+            } => {
+                *start_direction = start_direction.other();
+            }
+
+            EndTilingBehaviour::MasterStack {
+                stack_direction, ..
+            } => {
+                *stack_direction = stack_direction.other();
+            }
+
+            EndTilingBehaviour::Grid { preference, .. } => {
+                *preference = match preference {
+                    GridPreference::Rows => GridPreference::Columns,
+                    GridPreference::Columns => GridPreference::Rows,
+                };
+            }
+
+            _ => return None,
+        }
+
+        return Some(ret);
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -88,6 +303,7 @@ pub struct Zone {
     pub bottom: i32,
 }
 
+#[cfg(feature = "win32")]
 impl From<RECT> for Zone {
     fn from(value: RECT) -> Self {
         Zone {
@@ -116,9 +332,80 @@ impl Zone {
     pub fn h(&self) -> i32 {
         self.bottom - self.top
     }
+
+    pub fn shrunk(&self, by: &EdgePadding) -> Self {
+        Zone::new(
+            self.left + by.left,
+            self.top + by.top,
+            self.right - by.right,
+            self.bottom - by.bottom,
+        )
+    }
+
+    fn scaled(&self, from: &Zone, to: &Zone) -> Self {
+        Zone::new(
+            scale_coordinate(self.left, from.left, from.w(), to.left, to.w()),
+            scale_coordinate(self.top, from.top, from.h(), to.top, to.h()),
+            scale_coordinate(self.right, from.left, from.w(), to.left, to.w()),
+            scale_coordinate(self.bottom, from.top, from.h(), to.top, to.h()),
+        )
+    }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+fn scale_coordinate(
+    coordinate: i32,
+    from_start: i32,
+    from_len: i32,
+    to_start: i32,
+    to_len: i32,
+) -> i32 {
+    if from_len == to_len {
+        return coordinate - from_start + to_start;
+    }
+
+    return to_start
+        + (((coordinate - from_start) as f64 * to_len as f64) / from_len as f64).round() as i32;
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct EdgePadding {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+impl EdgePadding {
+    pub fn uniform(val: i32) -> Self {
+        EdgePadding {
+            left: val,
+            top: val,
+            right: val,
+            bottom: val,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct FrameInsets {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+impl Default for FrameInsets {
+    fn default() -> Self {
+        FrameInsets {
+            left: 7,
+            top: 0,
+            right: 7,
+            bottom: 7,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Position {
     pub x: i32,
     pub y: i32,
@@ -126,21 +413,63 @@ pub struct Position {
     pub cy: i32,
 }
 
+impl Position {
+    pub fn with_frame_insets(&self, from: &FrameInsets, to: &FrameInsets) -> Self {
+        Position {
+            x: self.x + from.left - to.left,
+            y: self.y + from.top - to.top,
+            cx: self.cx - from.left - from.right + to.left + to.right,
+            cy: self.cy - from.top - from.bottom + to.top + to.bottom,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Variant {
+    #[serde(flatten)]
+    metadata: Metadata,
     zones: Vec<Vec<Zone>>,
     manual_zones_until: usize,
     end_tiling_behaviour: EndTilingBehaviour,
+    #[serde(skip)]
     positions: Vec<Vec<Position>>,
+    #[serde(default)]
+    split_trees: Option<Vec<Option<SplitTree>>>,
+    #[serde(default)]
+    max_zones: Option<usize>,
+    #[serde(default)]
+    min_size: MinSize,
+    #[serde(default)]
+    zone_min_sizes: BTreeMap<usize, MinSize>,
+    #[serde(default)]
+    min_size_overflow: MinSizeOverflow,
+    #[serde(default)]
+    max_windows: Option<usize>,
+    #[serde(default)]
+    overflow_policy: OverflowPolicy,
+    #[serde(default = "default_auto_split_bias")]
+    auto_split_bias: f64,
+    #[serde(default, skip_serializing_if = "Transform::is_identity")]
+    transform: Transform,
 }
 
 impl Variant {
     pub fn new(w: i32, h: i32) -> Self {
         Variant {
+            metadata: Metadata::default(),
             zones: vec![vec![Zone::new(0, 0, w, h)]],
             manual_zones_until: 1,
             end_tiling_behaviour: EndTilingBehaviour::default_directional(),
             positions: Vec::new(),
+            split_trees: None,
+            max_zones: None,
+            min_size: MinSize::default(),
+            zone_min_sizes: BTreeMap::new(),
+            min_size_overflow: MinSizeOverflow::default(),
+            max_windows: None,
+            overflow_policy: OverflowPolicy::default(),
+            auto_split_bias: DEFAULT_AUTO_SPLIT_BIAS,
+            transform: Transform::default(),
         }
     }
 
@@ -155,9 +484,39 @@ impl Variant {
     pub fn delete_zones(&mut self, i: usize) {
         self.zones.remove(i);
 
+        if let Some(trees) = &mut self.split_trees {
+            if i < trees.len() {
+                trees.remove(i);
+            }
+        }
+
         self.manual_zones_until -= 1;
     }
 
+    pub fn insert_zone_vec(&mut self, i: usize, zones: Vec<Zone>, split_tree: Option<SplitTree>) {
+        self.zones.insert(i, zones);
+
+        if let Some(trees) = &mut self.split_trees {
+            if i <= trees.len() {
+                trees.insert(i, split_tree);
+            }
+        }
+
+        self.manual_zones_until += 1;
+    }
+
+    pub fn set_zone_vec(&mut self, i: usize, zones: Vec<Zone>, split_tree: Option<SplitTree>) {
+        self.zones[i] = zones;
+
+        if let Some(tree) = self.split_trees.as_mut().and_then(|trees| trees.get_mut(i)) {
+            *tree = split_tree;
+        }
+
+        if i < self.manual_zones_until {
+            self.reset_generated_zones();
+        }
+    }
+
     pub fn swap_zone_vectors(&mut self, i: usize, j: usize) {
         if i == j {
             return;
@@ -167,6 +526,16 @@ impl Variant {
 
         let second_idx = std::cmp::max(i, j);
 
+        match &mut self.split_trees {
+            Some(trees) if second_idx < trees.len() => trees.swap(first_idx, second_idx),
+
+            _ => {
+                self.detach_split_tree(first_idx);
+
+                self.detach_split_tree(second_idx);
+            }
+        }
+
         let (first_slice, second_slice) = self.zones.split_at_mut(second_idx);
 
         std::mem::swap(&mut first_slice[first_idx], &mut second_slice[0]);
@@ -184,57 +553,58 @@ impl Variant {
         self.positions.len()
     }
 
-    pub fn update(&mut self, window_padding: i32, edge_padding: i32, monitor_rect: &Zone) {
-        match &mut self.end_tiling_behaviour {
-            EndTilingBehaviour::Directional {
-                direction: _,
-                start_from,
-                from_zones,
-                zone_idx: _,
-            } if *start_from > 1 && matches!(from_zones, None) => {
-                *from_zones = self.zones.pop();
-
-                self.manual_zones_until -= 1;
-            }
-
-            _ => (),
-        }
+    pub fn update(
+        &mut self,
+        window_padding: i32,
+        edge_padding: &EdgePadding,
+        frame_insets: &FrameInsets,
+        smart_gaps: bool,
+        monitor_rect: &Zone,
+    ) {
+        self.take_from_zones();
 
         self.positions = Vec::new();
 
         let mut len = 0;
 
+        let no_edge_padding = EdgePadding::default();
+
         for zones in &self.zones {
             self.positions.push(Vec::new());
 
             len += 1;
 
+            let (window_padding, edge_padding) = match smart_gaps && zones.len() == 1 {
+                true => (0, &no_edge_padding),
+                false => (window_padding, edge_padding),
+            };
+
             for zone in zones {
                 let mut position = Position {
-                    x: zone.left - 7 + window_padding,
-                    y: zone.top + window_padding,
-                    cx: zone.w() + 14 - 2 * window_padding,
-                    cy: zone.h() + 7 - 2 * window_padding,
+                    x: zone.left - frame_insets.left + window_padding,
+                    y: zone.top - frame_insets.top + window_padding,
+                    cx: zone.w() + frame_insets.left + frame_insets.right - 2 * window_padding,
+                    cy: zone.h() + frame_insets.top + frame_insets.bottom - 2 * window_padding,
                 };
 
                 if zone.left == monitor_rect.left {
-                    position.x = position.x - window_padding + edge_padding;
+                    position.x = position.x - window_padding + edge_padding.left;
 
-                    position.cx = position.cx + window_padding - edge_padding;
+                    position.cx = position.cx + window_padding - edge_padding.left;
                 }
 
                 if zone.top == monitor_rect.top {
-                    position.y = position.y - window_padding + edge_padding;
+                    position.y = position.y - window_padding + edge_padding.top;
 
-                    position.cy = position.cy + window_padding - edge_padding;
+                    position.cy = position.cy + window_padding - edge_padding.top;
                 }
 
                 if zone.right == monitor_rect.right {
-                    position.cx = position.cx + window_padding - edge_padding;
+                    position.cx = position.cx + window_padding - edge_padding.right;
                 }
 
                 if zone.bottom == monitor_rect.bottom {
-                    position.cy = position.cy + window_padding - edge_padding;
+                    position.cy = position.cy + window_padding - edge_padding.bottom;
                 }
 
                 self.positions[len - 1].push(position);
@@ -242,8 +612,55 @@ impl Variant {
         }
     }
 
+    fn take_from_zones(&mut self) {
+        match &mut self.end_tiling_behaviour {
+            EndTilingBehaviour::Directional {
+                direction: _,
+                start_from,
+                from_zones,
+                zone_idx: _,
+            } if *start_from > 1 && matches!(from_zones, None) => {
+                *from_zones = self.zones.pop();
+
+                self.manual_zones_until -= 1;
+
+                if let Some(trees) = &mut self.split_trees {
+                    trees.truncate(self.manual_zones_until);
+                }
+            }
+
+            _ => (),
+        }
+    }
+
+    pub fn get_auto_split_bias(&self) -> f64 {
+        self.auto_split_bias
+    }
+
+    pub fn set_auto_split_bias(&mut self, val: f64) {
+        self.auto_split_bias = val;
+
+        if let Some(rect) = bounding_zone(&self.zones[0]) {
+            self.materialize_split_trees(&rect);
+        }
+
+        self.reset_generated_zones();
+    }
+
+    pub fn get_max_zones(&self) -> Option<usize> {
+        self.max_zones
+    }
+
+    pub fn set_max_zones(&mut self, val: Option<usize>) {
+        self.max_zones = val;
+
+        self.reset_generated_zones();
+    }
+
     pub fn set_end_tiling_behaviour(&mut self, behaviour: EndTilingBehaviour) {
         self.end_tiling_behaviour = behaviour;
+
+        self.reset_generated_zones();
     }
 
     pub fn get_end_zone_idx(&self) -> usize {
@@ -259,6 +676,18 @@ impl Variant {
                 splits: _,
                 zone_idx,
             } => return zone_idx,
+
+            EndTilingBehaviour::Spiral { zone_idx, .. } => return zone_idx,
+
+            EndTilingBehaviour::Dwindle { zone_idx, .. } => return zone_idx,
+
+            EndTilingBehaviour::Grid { zone_idx, .. } => return zone_idx,
+
+            EndTilingBehaviour::MasterStack { zone_idx, .. } => return zone_idx,
+
+            EndTilingBehaviour::CenteredMaster { zone_idx, .. } => return zone_idx,
+
+            EndTilingBehaviour::Monocle { zone_idx } => return zone_idx,
         }
     }
 
@@ -279,6 +708,30 @@ impl Variant {
             } => {
                 *zone_idx = i;
             }
+
+            EndTilingBehaviour::Spiral { zone_idx, .. } => {
+                *zone_idx = i;
+            }
+
+            EndTilingBehaviour::Dwindle { zone_idx, .. } => {
+                *zone_idx = i;
+            }
+
+            EndTilingBehaviour::Grid { zone_idx, .. } => {
+                *zone_idx = i;
+            }
+
+            EndTilingBehaviour::MasterStack { zone_idx, .. } => {
+                *zone_idx = i;
+            }
+
+            EndTilingBehaviour::CenteredMaster { zone_idx, .. } => {
+                *zone_idx = i;
+            }
+
+            EndTilingBehaviour::Monocle { zone_idx } => {
+                *zone_idx = i;
+            }
         }
     }
 
@@ -297,7 +750,33 @@ impl Variant {
                 splits: _,
                 zone_idx: _,
             } => return,
+
+            EndTilingBehaviour::Spiral {
+                start_direction, ..
+            } => {
+                *start_direction = new_direction;
+            }
+
+            EndTilingBehaviour::Dwindle {
+                start_direction, ..
+            } => {
+                *start_direction = new_direction;
+            }
+
+            EndTilingBehaviour::Grid { .. } => return,
+
+            EndTilingBehaviour::MasterStack {
+                stack_direction, ..
+            } => {
+                *stack_direction = new_direction;
+            }
+
+            EndTilingBehaviour::CenteredMaster { .. } => return,
+
+            EndTilingBehaviour::Monocle { .. } => return,
         }
+
+        self.reset_generated_zones();
     }
 
     pub fn set_end_tiling_start_from(&mut self, val: usize) {
@@ -311,10 +790,91 @@ impl Variant {
                 *start_from = val;
             }
 
-            EndTilingBehaviour::Repeating {
-                splits: _,
-                zone_idx: _,
-            } => return,
+            _ => return,
+        }
+
+        self.reset_generated_zones();
+    }
+
+    pub fn set_end_tiling_ratio(&mut self, val: f64) {
+        match &mut self.end_tiling_behaviour {
+            EndTilingBehaviour::Spiral { ratio, .. } => {
+                *ratio = val;
+            }
+
+            EndTilingBehaviour::Dwindle { ratio, .. } => {
+                *ratio = val;
+            }
+
+            EndTilingBehaviour::MasterStack { master_ratio, .. } => {
+                *master_ratio = val;
+            }
+
+            EndTilingBehaviour::CenteredMaster { center_ratio, .. } => {
+                *center_ratio = val;
+            }
+
+            _ => return,
+        }
+
+        self.reset_generated_zones();
+    }
+
+    pub fn adjust_master_ratio(&mut self, delta: f64) {
+        match &mut self.end_tiling_behaviour {
+            EndTilingBehaviour::MasterStack { master_ratio, .. } => {
+                *master_ratio = (*master_ratio + delta).clamp(0.05, 0.95);
+            }
+
+            EndTilingBehaviour::CenteredMaster { center_ratio, .. } => {
+                *center_ratio = (*center_ratio + delta).clamp(0.05, 0.95);
+            }
+
+            _ => return,
+        }
+
+        self.reset_generated_zones();
+    }
+
+    pub fn set_master_count(&mut self, val: usize) {
+        match &mut self.end_tiling_behaviour {
+            EndTilingBehaviour::MasterStack { master_count, .. } => {
+                *master_count = val;
+            }
+
+            _ => return,
+        }
+
+        self.reset_generated_zones();
+    }
+
+    pub fn adjust_master_count(&mut self, delta: i32) {
+        if let EndTilingBehaviour::MasterStack { master_count, .. } = self.end_tiling_behaviour {
+            self.set_master_count(master_count.saturating_add_signed(delta as isize));
+        }
+    }
+
+    pub fn set_grid_preference(&mut self, val: GridPreference) {
+        if let EndTilingBehaviour::Grid { preference, .. } = &mut self.end_tiling_behaviour {
+            *preference = val;
+
+            self.reset_generated_zones();
+        }
+    }
+
+    pub fn set_grid_fill(&mut self, val: GridFill) {
+        if let EndTilingBehaviour::Grid { fill, .. } = &mut self.end_tiling_behaviour {
+            *fill = val;
+
+            self.reset_generated_zones();
+        }
+    }
+
+    pub fn set_end_tiling_clockwise(&mut self, val: bool) {
+        if let EndTilingBehaviour::Spiral { clockwise, .. } = &mut self.end_tiling_behaviour {
+            *clockwise = val;
+
+            self.reset_generated_zones();
         }
     }
 
@@ -349,6 +909,8 @@ impl Variant {
                     swap,
                 ));
             }
+
+            self.reset_generated_zones();
         }
     }
 
@@ -359,6 +921,8 @@ impl Variant {
         } = &mut self.end_tiling_behaviour
         {
             splits[i].remove(j);
+
+            self.reset_generated_zones();
         }
     }
 
@@ -369,6 +933,8 @@ impl Variant {
         } = &mut self.end_tiling_behaviour
         {
             splits[i][j].direction = direction;
+
+            self.reset_generated_zones();
         }
     }
 
@@ -379,6 +945,8 @@ impl Variant {
         } = &mut self.end_tiling_behaviour
         {
             splits[i][j].split_ratio = val;
+
+            self.reset_generated_zones();
         }
     }
 
@@ -389,6 +957,8 @@ impl Variant {
         } = &mut self.end_tiling_behaviour
         {
             splits[i][j].split_idx_offset = val;
+
+            self.reset_generated_zones();
         }
     }
 
@@ -399,22 +969,36 @@ impl Variant {
         } = &mut self.end_tiling_behaviour
         {
             splits[i][j].swap = val;
+
+            self.reset_generated_zones();
         }
     }
 
     pub fn new_zone_vec(&mut self, w: i32, h: i32) {
         self.zones.push(vec![Zone::new(0, 0, w, h)]);
 
+        if let Some(trees) = &mut self.split_trees {
+            trees.push(Some(SplitTree::Leaf));
+        }
+
         self.manual_zones_until += 1;
     }
 
     pub fn clone_zone_vec(&mut self, i: usize) {
         self.zones.push(self.zones[i].clone());
 
+        if let Some(trees) = &mut self.split_trees {
+            let tree = trees.get(i).cloned().flatten();
+
+            trees.push(tree);
+        }
+
         self.manual_zones_until += 1;
     }
 
     pub fn split(&mut self, i: usize, j: usize, direction: SplitDirection) {
+        self.detach_split_tree(i);
+
         let zone = &mut self.zones[i][j];
 
         let new_zone;
@@ -500,6 +1084,8 @@ impl Variant {
             return;
         }
 
+        self.detach_split_tree(i);
+
         self.zones[i].remove(second_idx);
     }
 
@@ -512,6 +1098,8 @@ impl Variant {
 
         let second_idx = std::cmp::max(j, k);
 
+        self.detach_split_tree(i);
+
         let (first_slice, second_slice) = self.zones[i].split_at_mut(second_idx);
 
         std::mem::swap(&mut first_slice[first_idx], &mut second_slice[0]);
@@ -537,11 +1125,95 @@ impl Variant {
         self.zones[i].insert(second_idx, zone);
     }
 
+    pub fn scaled_to(&self, from: &Zone, to: &Zone) -> Variant {
+        let mut end_tiling_behaviour = self.end_tiling_behaviour.clone();
+
+        if let EndTilingBehaviour::Directional {
+            from_zones: Some(from_zones),
+            ..
+        } = &mut end_tiling_behaviour
+        {
+            for zone in from_zones.iter_mut() {
+                *zone = zone.scaled(from, to);
+            }
+        }
+
+        let mut ret = Variant {
+            metadata: self.metadata.clone(),
+            zones: self
+                .zones
+                .iter()
+                .map(|zones| zones.iter().map(|zone| zone.scaled(from, to)).collect())
+                .collect(),
+            manual_zones_until: self.manual_zones_until,
+            end_tiling_behaviour,
+            positions: Vec::new(),
+            split_trees: self.split_trees.clone(),
+            max_zones: self.max_zones,
+            min_size: self.min_size.clone(),
+            zone_min_sizes: self.zone_min_sizes.clone(),
+            min_size_overflow: self.min_size_overflow.clone(),
+            max_windows: self.max_windows,
+            overflow_policy: self.overflow_policy.clone(),
+            auto_split_bias: self.auto_split_bias,
+            transform: self.transform.clone(),
+        };
+
+        ret.materialize_split_trees(to);
+
+        ret
+    }
+
     pub fn extend(&mut self) {
-        let end_zone_idx = self.get_end_zone_idx();
+        if !self.transform.is_identity() {
+            self.extend_transformed();
+
+            return;
+        }
+
+        let last_zones = &self.zones[self.zones.len() - 1];
+
+        if self
+            .stacks_from()
+            .is_some_and(|stacks_from| self.zones.len() >= stacks_from)
+            || (self.zones.len() > self.manual_zones_until && has_stacked_zones(last_zones))
+        {
+            self.push_stacked_zone_vec(last_zones.len() - 1);
+
+            return;
+        }
 
         let end_tiling_behaviour = self.end_tiling_behaviour.clone();
 
+        self.extend_with(end_tiling_behaviour.clone());
+
+        if self.new_zones_fit_min_sizes() {
+            return;
+        }
+
+        self.zones.pop();
+
+        if self.min_size_overflow == MinSizeOverflow::SwitchDirection {
+            if let Some(other) = self
+                .with_resolved_direction(&end_tiling_behaviour)
+                .with_other_direction()
+            {
+                self.extend_with(other);
+
+                if self.new_zones_fit_min_sizes() {
+                    return;
+                }
+
+                self.zones.pop();
+            }
+        }
+
+        self.push_stacked_zone_vec(self.zones[self.zones.len() - 1].len() - 1);
+    }
+
+    fn extend_with(&mut self, end_tiling_behaviour: EndTilingBehaviour) {
+        let end_zone_idx = self.get_end_zone_idx();
+
         match end_tiling_behaviour {
             EndTilingBehaviour::Directional {
                 direction,
@@ -560,6 +1232,11 @@ impl Variant {
                     }
                 }
 
+                let direction = direction.resolve(
+                    &self.zones[self.zones.len() - 1][zone_idx],
+                    self.auto_split_bias,
+                );
+
                 match direction {
                     Direction::Horizontal => {
                         let offset = (self.zones[self.zones.len() - 1][zone_idx].w())
@@ -579,7 +1256,7 @@ impl Variant {
                         }
                     }
 
-                    Direction::Vertical => {
+                    Direction::Vertical | Direction::Auto => {
                         let offset = (self.zones[self.zones.len() - 1][zone_idx].h())
                             / (self.zones.len() - self.zones[self.zones.len() - 1].len() + 1)
                                 as i32;
@@ -633,7 +1310,12 @@ impl Variant {
 
                     let at;
 
-                    match split.direction {
+                    let direction = split.direction.resolve(
+                        &self.zones[self.zones.len() - 1][split_idx],
+                        self.auto_split_bias,
+                    );
+
+                    match direction {
                         Direction::Horizontal => {
                             at = self.zones[self.zones.len() - 1][split_idx].left
                                 + (split.split_ratio
@@ -647,7 +1329,7 @@ impl Variant {
                             );
                         }
 
-                        Direction::Vertical => {
+                        Direction::Vertical | Direction::Auto => {
                             at = self.zones[self.zones.len() - 1][split_idx].top
                                 + (split.split_ratio
                                     * (self.zones[self.zones.len() - 1][split_idx].h() as f64))
@@ -672,12 +1354,264 @@ impl Variant {
                     }
                 }
             }
+
+            EndTilingBehaviour::Spiral {
+                ratio,
+                clockwise,
+                start_direction,
+                zone_idx,
+            } => {
+                let step = self.zones.len() - self.manual_zones_until;
+
+                let direction = self.halving_direction(&start_direction, zone_idx);
+
+                let starts_horizontal = (direction == Direction::Horizontal) != (step % 2 == 1);
+
+                let new_zone_first = match clockwise == starts_horizontal {
+                    true => step % 4 >= 2,
+                    false => step % 4 == 1 || step % 4 == 2,
+                };
+
+                self.extend_by_halving(ratio, &direction, zone_idx, new_zone_first);
+            }
+
+            EndTilingBehaviour::Dwindle {
+                ratio,
+                start_direction,
+                zone_idx,
+            } => {
+                let direction = self.halving_direction(&start_direction, zone_idx);
+
+                self.extend_by_halving(ratio, &direction, zone_idx, false);
+            }
+
+            EndTilingBehaviour::Grid {
+                preference,
+                fill,
+                zone_idx,
+            } => {
+                self.zones
+                    .push(self.zones[self.manual_zones_until - 1].clone());
+
+                let i = self.zones.len() - 1;
+
+                let cells_len = self.zones.len() - self.manual_zones_until + 1;
+
+                let mut cells = grid_cells(&self.zones[i][zone_idx], cells_len, &preference, &fill);
+
+                self.zones[i][zone_idx] = cells.remove(0);
+
+                self.zones[i].append(&mut cells);
+            }
+
+            EndTilingBehaviour::MasterStack {
+                master_count,
+                master_ratio,
+                stack_direction,
+                master_side,
+                zone_idx,
+            } => {
+                self.zones
+                    .push(self.zones[self.manual_zones_until - 1].clone());
+
+                let i = self.zones.len() - 1;
+
+                let cells_len = self.zones.len() - self.manual_zones_until + 1;
+
+                let mut cells = master_stack_cells(
+                    &self.zones[i][zone_idx],
+                    cells_len,
+                    master_count,
+                    master_ratio,
+                    &stack_direction,
+                    master_side,
+                    self.auto_split_bias,
+                );
+
+                self.zones[i][zone_idx] = cells.remove(0);
+
+                self.zones[i].append(&mut cells);
+            }
+
+            EndTilingBehaviour::CenteredMaster {
+                center_ratio,
+                zone_idx,
+            } => {
+                self.zones
+                    .push(self.zones[self.manual_zones_until - 1].clone());
+
+                let i = self.zones.len() - 1;
+
+                let cells_len = self.zones.len() - self.manual_zones_until + 1;
+
+                let mut cells =
+                    centered_master_cells(&self.zones[i][zone_idx], cells_len, center_ratio);
+
+                self.zones[i][zone_idx] = cells.remove(0);
+
+                self.zones[i].append(&mut cells);
+            }
+
+            EndTilingBehaviour::Monocle { zone_idx } => {
+                self.push_stacked_zone_vec(zone_idx);
+            }
+        }
+    }
+
+    fn push_stacked_zone_vec(&mut self, j: usize) {
+        let mut zones = self.zones[self.zones.len() - 1].clone();
+
+        zones.push(zones[j].clone());
+
+        self.zones.push(zones);
+    }
+
+    fn halving_zone_idx(&self, zone_idx: usize) -> usize {
+        match self.zones.len() - self.manual_zones_until {
+            0 => zone_idx,
+            _ => self.zones[self.zones.len() - 1].len() - 1,
+        }
+    }
+
+    fn halving_direction(&self, start_direction: &Direction, zone_idx: usize) -> Direction {
+        let direction = match (self.zones.len() - self.manual_zones_until) % 2 {
+            0 => start_direction.clone(),
+            _ => start_direction.other(),
+        };
+
+        direction.resolve(
+            &self.zones[self.zones.len() - 1][self.halving_zone_idx(zone_idx)],
+            self.auto_split_bias,
+        )
+    }
+
+    fn with_resolved_direction(
+        &self,
+        end_tiling_behaviour: &EndTilingBehaviour,
+    ) -> EndTilingBehaviour {
+        let mut ret = end_tiling_behaviour.clone();
+
+        match &mut ret {
+            EndTilingBehaviour::Directional {
+                direction,
+                start_from,
+                from_zones,
+                zone_idx,
+            } => {
+                let zone = match start_from {
+                    1 => &self.zones[self.manual_zones_until - 1][*zone_idx],
+
+                    _ => &from_zones.as_ref().unwrap()[*zone_idx],
+                };
+
+                *direction = direction.resolve(zone, self.auto_split_bias);
+            }
+
+            EndTilingBehaviour::Spiral {
+                start_direction,
+                zone_idx,
+                ..
+            }
+            | EndTilingBehaviour::Dwindle {
+                start_direction,
+                zone_idx,
+                ..
+            } if *start_direction == Direction::Auto => {
+                let direction = self.halving_direction(start_direction, *zone_idx);
+
+                *start_direction = match (self.zones.len() - self.manual_zones_until) % 2 {
+                    0 => direction,
+                    _ => direction.other(),
+                };
+            }
+
+            EndTilingBehaviour::MasterStack {
+                master_count,
+                master_ratio,
+                stack_direction,
+                master_side,
+                zone_idx,
+            } => {
+                let rect = &self.zones[self.manual_zones_until - 1][*zone_idx];
+
+                let cells_len = self.zones.len() - self.manual_zones_until + 1;
+
+                let zone = match *master_count == 0 || cells_len <= *master_count {
+                    true => rect.clone(),
+
+                    false => master_and_stack(rect, *master_ratio, *master_side).1,
+                };
+
+                *stack_direction = stack_direction.resolve(&zone, self.auto_split_bias);
+            }
+
+            _ => (),
+        }
+
+        return ret;
+    }
+
+    fn extend_by_halving(
+        &mut self,
+        ratio: f64,
+        direction: &Direction,
+        zone_idx: usize,
+        new_zone_first: bool,
+    ) {
+        let j = self.halving_zone_idx(zone_idx);
+
+        self.zones.push(self.zones[self.zones.len() - 1].clone());
+
+        let i = self.zones.len() - 1;
+
+        let zone = &mut self.zones[i][j];
+
+        let new_zone;
+
+        match direction {
+            Direction::Horizontal => {
+                if new_zone_first {
+                    let at = zone.right - (ratio * zone.w() as f64).round() as i32;
+
+                    new_zone = Zone::new(zone.left, zone.top, at, zone.bottom);
+
+                    zone.left = at;
+                } else {
+                    let at = zone.left + (ratio * zone.w() as f64).round() as i32;
+
+                    new_zone = Zone::new(at, zone.top, zone.right, zone.bottom);
+
+                    zone.right = at;
+                }
+            }
+
+            Direction::Vertical | Direction::Auto => {
+                if new_zone_first {
+                    let at = zone.bottom - (ratio * zone.h() as f64).round() as i32;
+
+                    new_zone = Zone::new(zone.left, zone.top, zone.right, at);
+
+                    zone.top = at;
+                } else {
+                    let at = zone.top + (ratio * zone.h() as f64).round() as i32;
+
+                    new_zone = Zone::new(zone.left, at, zone.right, zone.bottom);
+
+                    zone.bottom = at;
+                }
+            }
         }
+
+        self.zones[i].push(new_zone);
     }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Layout {
+    #[serde(default)]
+    version: u32,
+    #[serde(flatten)]
+    metadata: Metadata,
     monitor_rect: Zone,
     variants: Vec<Variant>,
     default_variant_idx: usize,
@@ -686,12 +1620,18 @@ pub struct Layout {
 impl Layout {
     pub fn new(w: i32, h: i32) -> Self {
         Layout {
+            version: LAYOUT_VERSION,
+            metadata: Metadata::default(),
             monitor_rect: Zone::new(0, 0, w, h),
             variants: vec![Variant::new(w, h)],
             default_variant_idx: 0,
         }
     }
 
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
     pub fn get_monitor_rect(&self) -> &Zone {
         &self.monitor_rect
     }
@@ -720,9 +1660,36 @@ impl Layout {
         self.default_variant_idx = i;
     }
 
-    pub fn update_all(&mut self, window_padding: i32, edge_padding: i32, monitor_rect: &Zone) {
+    pub fn scaled_to(&self, work_area: &Zone) -> Layout {
+        Layout {
+            version: self.version,
+            metadata: self.metadata.clone(),
+            monitor_rect: work_area.clone(),
+            variants: self
+                .variants
+                .iter()
+                .map(|variant| variant.scaled_to(&self.monitor_rect, work_area))
+                .collect(),
+            default_variant_idx: self.default_variant_idx,
+        }
+    }
+
+    pub fn update_all(
+        &mut self,
+        window_padding: i32,
+        edge_padding: &EdgePadding,
+        frame_insets: &FrameInsets,
+        smart_gaps: bool,
+        monitor_rect: &Zone,
+    ) {
         for variant in self.variants.iter_mut() {
-            variant.update(window_padding, edge_padding, monitor_rect);
+            variant.update(
+                window_padding,
+                edge_padding,
+                frame_insets,
+                smart_gaps,
+                monitor_rect,
+            );
         }
     }
 
@@ -737,6 +1704,14 @@ impl Layout {
         self.variants.push(self.variants[idx].clone());
     }
 
+    pub fn insert_variant(&mut self, idx: usize, variant: Variant) {
+        self.variants.insert(idx, variant);
+
+        if idx <= self.default_variant_idx {
+            self.default_variant_idx += 1;
+        }
+    }
+
     pub fn swap_variants(&mut self, i: usize, j: usize) {
         if i == j {
             return;
@@ -0,0 +1,88 @@
+use crate::*;
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+pub struct Metadata {
+    pub name: String,
+    pub description: String,
+    pub tags: Vec<String>,
+}
+
+impl Metadata {
+    pub fn named(name: &str) -> Self {
+        Metadata {
+            name: String::from(name),
+            ..Default::default()
+        }
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|val| val == tag)
+    }
+}
+
+impl Variant {
+    pub fn get_metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    pub fn set_metadata(&mut self, val: Metadata) {
+        self.metadata = val;
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.metadata.name
+    }
+
+    pub fn set_name(&mut self, val: &str) {
+        self.metadata.name = String::from(val);
+    }
+
+    pub fn label(&self, variant_idx: usize) -> String {
+        match self.metadata.name.is_empty() {
+            true => format!("variant {}", variant_idx),
+
+            false => format!("variant '{}'", self.metadata.name),
+        }
+    }
+}
+
+impl Layout {
+    pub fn get_metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    pub fn set_metadata(&mut self, val: Metadata) {
+        self.metadata = val;
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.metadata.name
+    }
+
+    pub fn set_name(&mut self, val: &str) {
+        self.metadata.name = String::from(val);
+    }
+
+    pub fn find_variant(&self, name: &str) -> Option<usize> {
+        self.variants
+            .iter()
+            .position(|variant| variant.metadata.name == name)
+    }
+
+    pub fn label(&self) -> String {
+        match self.metadata.name.is_empty() {
+            true => String::from("unnamed layout"),
+
+            false => format!("Layout '{}'", self.metadata.name),
+        }
+    }
+
+    pub fn variant_label(&self, variant_idx: usize) -> String {
+        match self.variants.get(variant_idx) {
+            Some(variant) => format!("{} / {}", self.label(), variant.label(variant_idx)),
+
+            None => self.label(),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::*;
+
+use serde_json::Value;
+
+pub const LAYOUT_VERSION: u32 = 1;
+
+const MIGRATIONS: [fn(&mut Value); LAYOUT_VERSION as usize] = [migrate_v0_to_v1];
+
+fn migrate_v0_to_v1(layout: &mut Value) {
+    if let Some(variants) = layout.get_mut("variants").and_then(Value::as_array_mut) {
+        for variant in variants {
+            if let Some(variant) = variant.as_object_mut() {
+                variant.remove("positions");
+            }
+        }
+    }
+}
+
+pub(crate) fn invalid_json(error: serde_json::Error) -> LayoutError {
+    LayoutError::InvalidJson {
+        message: error.to_string(),
+    }
+}
+
+pub fn migrate_layout(layout: &mut Value) -> Result<bool, LayoutError> {
+    let version = match layout.get("version") {
+        Some(val) => match val.as_u64() {
+            Some(val) => val,
+
+            None => {
+                return Err(LayoutError::InvalidJson {
+                    message: String::from("version is not an unsigned integer"),
+                })
+            }
+        },
+
+        None => 0,
+    };
+
+    if version > LAYOUT_VERSION as u64 {
+        return Err(LayoutError::UnsupportedVersion { version });
+    }
+
+    for migration in MIGRATIONS.iter().skip(version as usize) {
+        migration(layout);
+    }
+
+    if let Some(layout) = layout.as_object_mut() {
+        layout.insert(String::from("version"), Value::from(LAYOUT_VERSION));
+    }
+
+    return Ok(version < LAYOUT_VERSION as u64);
+}
+
+impl Layout {
+    pub fn from_json(bytes: &[u8]) -> Result<(Layout, bool), LayoutError> {
+        let mut value: Value = serde_json::from_slice(bytes).map_err(invalid_json)?;
+
+        let migrated = migrate_layout(&mut value)?;
+
+        let layout = serde_json::from_value(value).map_err(invalid_json)?;
+
+        return Ok((layout, migrated));
+    }
+
+    pub fn to_json(&self) -> Result<Vec<u8>, LayoutError> {
+        serde_json::to_vec_pretty(self).map_err(invalid_json)
+    }
+}
@@ -0,0 +1,87 @@
+use crate::*;
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct MinSize {
+    pub width: i32,
+    pub height: i32,
+}
+
+impl MinSize {
+    pub fn new(width: i32, height: i32) -> Self {
+        MinSize { width, height }
+    }
+
+    pub fn fits(&self, zone: &Zone) -> bool {
+        zone.w() >= self.width && zone.h() >= self.height
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum MinSizeOverflow {
+    #[default]
+    Stack,
+    SwitchDirection,
+}
+
+pub(crate) fn has_stacked_zones(zones: &[Zone]) -> bool {
+    for (j, zone) in zones.iter().enumerate() {
+        if zones.iter().skip(j + 1).any(|other| other == zone) {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+impl Variant {
+    pub fn get_min_size(&self) -> &MinSize {
+        &self.min_size
+    }
+
+    pub fn set_min_size(&mut self, val: MinSize) {
+        self.min_size = val;
+
+        self.reset_generated_zones();
+    }
+
+    pub fn get_zone_min_sizes(&self) -> &BTreeMap<usize, MinSize> {
+        &self.zone_min_sizes
+    }
+
+    pub fn set_zone_min_size(&mut self, j: usize, val: Option<MinSize>) {
+        match val {
+            Some(val) => {
+                self.zone_min_sizes.insert(j, val);
+            }
+
+            None => {
+                self.zone_min_sizes.remove(&j);
+            }
+        }
+
+        self.reset_generated_zones();
+    }
+
+    pub fn get_min_size_overflow(&self) -> &MinSizeOverflow {
+        &self.min_size_overflow
+    }
+
+    pub fn set_min_size_overflow(&mut self, val: MinSizeOverflow) {
+        self.min_size_overflow = val;
+
+        self.reset_generated_zones();
+    }
+
+    pub fn min_size_at(&self, j: usize) -> &MinSize {
+        self.zone_min_sizes.get(&j).unwrap_or(&self.min_size)
+    }
+
+    pub(crate) fn new_zones_fit_min_sizes(&self) -> bool {
+        let i = self.zones.len() - 1;
+
+        return self.zones[i]
+            .iter()
+            .enumerate()
+            .all(|(j, zone)| self.zones[i - 1].contains(zone) || self.min_size_at(j).fits(zone));
+    }
+}
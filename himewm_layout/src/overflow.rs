@@ -0,0 +1,61 @@
+use crate::*;
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum OverflowPolicy {
+    Float,
+    #[default]
+    Stack,
+    Spill,
+    MinimiseOldest,
+}
+
+impl Variant {
+    pub fn get_max_windows(&self) -> Option<usize> {
+        self.max_windows
+    }
+
+    pub fn set_max_windows(&mut self, val: Option<usize>) {
+        self.max_windows = val;
+
+        self.reset_generated_zones();
+    }
+
+    pub fn get_overflow_policy(&self) -> &OverflowPolicy {
+        &self.overflow_policy
+    }
+
+    pub fn set_overflow_policy(&mut self, val: OverflowPolicy) {
+        self.overflow_policy = val;
+
+        self.reset_generated_zones();
+    }
+
+    pub fn overflow_len(&self, windows_len: usize) -> usize {
+        match self.max_windows {
+            Some(max_windows) if self.overflow_policy != OverflowPolicy::Stack => {
+                windows_len.saturating_sub(max_windows)
+            }
+
+            _ => 0,
+        }
+    }
+
+    pub fn has_room_for(&self, windows_len: usize) -> bool {
+        self.max_windows
+            .is_none_or(|max_windows| windows_len < max_windows)
+    }
+
+    pub(crate) fn stacks_from(&self) -> Option<usize> {
+        let max_windows = match self.overflow_policy {
+            OverflowPolicy::Stack => self.max_windows,
+
+            _ => None,
+        };
+
+        match (self.max_zones, max_windows) {
+            (Some(max_zones), Some(max_windows)) => Some(std::cmp::min(max_zones, max_windows)),
+
+            (max_zones, max_windows) => max_zones.or(max_windows),
+        }
+    }
+}
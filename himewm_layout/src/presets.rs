@@ -0,0 +1,81 @@
+use crate::*;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum Preset {
+    Tall,
+    Wide,
+    Grid,
+    Spiral,
+    ThreeColumn,
+    Monocle,
+}
+
+impl Preset {
+    pub const ALL: [Preset; 6] = [
+        Preset::Tall,
+        Preset::Wide,
+        Preset::Grid,
+        Preset::Spiral,
+        Preset::ThreeColumn,
+        Preset::Monocle,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Preset::Tall => "tall",
+
+            Preset::Wide => "wide",
+
+            Preset::Grid => "grid",
+
+            Preset::Spiral => "spiral",
+
+            Preset::ThreeColumn => "three-column",
+
+            Preset::Monocle => "monocle",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Preset> {
+        Preset::ALL.into_iter().find(|preset| preset.name() == name)
+    }
+
+    pub fn dsl(&self) -> &'static str {
+        match self {
+            Preset::Tall => concat!(
+                "* => meta(name=\"master-stack\") master_stack(stack=v, side=left)\n",
+                "* => meta(name=\"monocle\") monocle()",
+            ),
+
+            Preset::Wide => concat!(
+                "* => meta(name=\"master-stack\") master_stack(stack=h, side=top)\n",
+                "* => meta(name=\"monocle\") monocle()",
+            ),
+
+            Preset::Grid => concat!(
+                "* => meta(name=\"columns\") grid(prefer=columns)\n",
+                "* => meta(name=\"rows\") grid(prefer=rows)",
+            ),
+
+            Preset::Spiral => concat!(
+                "* => meta(name=\"spiral\") spiral()\n",
+                "* => meta(name=\"dwindle\") dwindle()",
+            ),
+
+            Preset::ThreeColumn => concat!(
+                "* => meta(name=\"centered-master\") centered_master(ratio=0.5)\n",
+                "* => meta(name=\"monocle\") monocle()",
+            ),
+
+            Preset::Monocle => "* => meta(name=\"monocle\") monocle()",
+        }
+    }
+
+    pub fn layout(&self, work_area: &Zone) -> Layout {
+        let mut ret = Layout::parse_dsl(self.dsl(), work_area).unwrap();
+
+        ret.set_name(self.name());
+
+        return ret;
+    }
+}
@@ -0,0 +1,203 @@
+use crate::*;
+
+fn to_fraction(coordinate: i32, start: i32, len: i32) -> f64 {
+    (coordinate - start) as f64 / len as f64
+}
+
+fn to_coordinate(fraction: f64, start: i32, len: i32) -> i32 {
+    start + (fraction * len as f64).round() as i32
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RelativeZone {
+    pub left: f64,
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+}
+
+impl RelativeZone {
+    pub fn from_zone(zone: &Zone, work_area: &Zone) -> Self {
+        RelativeZone {
+            left: to_fraction(zone.left, work_area.left, work_area.w()),
+            top: to_fraction(zone.top, work_area.top, work_area.h()),
+            right: to_fraction(zone.right, work_area.left, work_area.w()),
+            bottom: to_fraction(zone.bottom, work_area.top, work_area.h()),
+        }
+    }
+
+    pub fn to_zone(&self, work_area: &Zone) -> Zone {
+        Zone::new(
+            to_coordinate(self.left, work_area.left, work_area.w()),
+            to_coordinate(self.top, work_area.top, work_area.h()),
+            to_coordinate(self.right, work_area.left, work_area.w()),
+            to_coordinate(self.bottom, work_area.top, work_area.h()),
+        )
+    }
+}
+
+fn to_relative_zones(zones: &[Zone], work_area: &Zone) -> Vec<RelativeZone> {
+    zones
+        .iter()
+        .map(|zone| RelativeZone::from_zone(zone, work_area))
+        .collect()
+}
+
+fn to_zones(zones: &[RelativeZone], work_area: &Zone) -> Vec<Zone> {
+    zones.iter().map(|zone| zone.to_zone(work_area)).collect()
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RelativeVariant {
+    #[serde(flatten)]
+    metadata: Metadata,
+    zones: Vec<Vec<RelativeZone>>,
+    from_zones: Option<Vec<RelativeZone>>,
+    manual_zones_until: usize,
+    end_tiling_behaviour: EndTilingBehaviour,
+    #[serde(default)]
+    split_trees: Option<Vec<Option<SplitTree>>>,
+    #[serde(default)]
+    max_zones: Option<usize>,
+    #[serde(default)]
+    min_size: MinSize,
+    #[serde(default)]
+    zone_min_sizes: BTreeMap<usize, MinSize>,
+    #[serde(default)]
+    min_size_overflow: MinSizeOverflow,
+    #[serde(default)]
+    max_windows: Option<usize>,
+    #[serde(default)]
+    overflow_policy: OverflowPolicy,
+    #[serde(default = "default_auto_split_bias")]
+    auto_split_bias: f64,
+    #[serde(default, skip_serializing_if = "Transform::is_identity")]
+    transform: Transform,
+}
+
+impl RelativeVariant {
+    pub fn get_zones(&self) -> &Vec<Vec<RelativeZone>> {
+        &self.zones
+    }
+
+    pub fn to_variant(&self, work_area: &Zone) -> Variant {
+        let mut end_tiling_behaviour = self.end_tiling_behaviour.clone();
+
+        if let EndTilingBehaviour::Directional { from_zones, .. } = &mut end_tiling_behaviour {
+            *from_zones = self
+                .from_zones
+                .as_ref()
+                .map(|zones| to_zones(zones, work_area));
+        }
+
+        Variant {
+            metadata: self.metadata.clone(),
+            zones: self
+                .zones
+                .iter()
+                .map(|zones| to_zones(zones, work_area))
+                .collect(),
+            manual_zones_until: self.manual_zones_until,
+            end_tiling_behaviour,
+            positions: Vec::new(),
+            split_trees: self.split_trees.clone(),
+            max_zones: self.max_zones,
+            min_size: self.min_size.clone(),
+            zone_min_sizes: self.zone_min_sizes.clone(),
+            min_size_overflow: self.min_size_overflow.clone(),
+            max_windows: self.max_windows,
+            overflow_policy: self.overflow_policy.clone(),
+            auto_split_bias: self.auto_split_bias,
+            transform: self.transform.clone(),
+        }
+    }
+}
+
+impl Variant {
+    pub fn to_relative(&self, work_area: &Zone) -> RelativeVariant {
+        let mut end_tiling_behaviour = self.end_tiling_behaviour.clone();
+
+        let from_zones = match &mut end_tiling_behaviour {
+            EndTilingBehaviour::Directional { from_zones, .. } => from_zones
+                .take()
+                .map(|zones| to_relative_zones(&zones, work_area)),
+
+            _ => None,
+        };
+
+        RelativeVariant {
+            metadata: self.metadata.clone(),
+            zones: self
+                .zones
+                .iter()
+                .map(|zones| to_relative_zones(zones, work_area))
+                .collect(),
+            from_zones,
+            manual_zones_until: self.manual_zones_until,
+            end_tiling_behaviour,
+            split_trees: self.split_trees.clone(),
+            max_zones: self.max_zones,
+            min_size: self.min_size.clone(),
+            zone_min_sizes: self.zone_min_sizes.clone(),
+            min_size_overflow: self.min_size_overflow.clone(),
+            max_windows: self.max_windows,
+            overflow_policy: self.overflow_policy.clone(),
+            auto_split_bias: self.auto_split_bias,
+            transform: self.transform.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RelativeLayout {
+    #[serde(flatten)]
+    metadata: Metadata,
+    variants: Vec<RelativeVariant>,
+    default_variant_idx: usize,
+}
+
+impl RelativeLayout {
+    pub fn get_variants(&self) -> &Vec<RelativeVariant> {
+        &self.variants
+    }
+
+    pub fn default_variant_idx(&self) -> usize {
+        self.default_variant_idx
+    }
+
+    pub fn from_json(bytes: &[u8]) -> Result<RelativeLayout, LayoutError> {
+        serde_json::from_slice(bytes).map_err(invalid_json)
+    }
+
+    pub fn to_json(&self) -> Result<Vec<u8>, LayoutError> {
+        serde_json::to_vec_pretty(self).map_err(invalid_json)
+    }
+
+    pub fn to_layout(&self, work_area: &Zone) -> Layout {
+        Layout {
+            version: LAYOUT_VERSION,
+            metadata: self.metadata.clone(),
+            monitor_rect: work_area.clone(),
+            variants: self
+                .variants
+                .iter()
+                .map(|variant| variant.to_variant(work_area))
+                .collect(),
+            default_variant_idx: self.default_variant_idx,
+        }
+    }
+}
+
+impl Layout {
+    pub fn to_relative(&self) -> RelativeLayout {
+        RelativeLayout {
+            metadata: self.metadata.clone(),
+            variants: self
+                .variants
+                .iter()
+                .map(|variant| variant.to_relative(&self.monitor_rect))
+                .collect(),
+            default_variant_idx: self.default_variant_idx,
+        }
+    }
+}
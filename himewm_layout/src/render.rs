@@ -0,0 +1,158 @@
+use crate::*;
+
+fn labelled_zones(zones: &[Zone]) -> Vec<(Zone, String)> {
+    let mut ret: Vec<(Zone, String)> = Vec::new();
+
+    for (j, zone) in zones.iter().enumerate() {
+        match ret.iter_mut().find(|(other, _)| other == zone) {
+            Some((_, label)) => label.push_str(&format!(",{}", j)),
+
+            None => ret.push((zone.clone(), j.to_string())),
+        }
+    }
+
+    return ret;
+}
+
+fn scale(coordinate: i32, start: i32, len: i32, cells: usize) -> usize {
+    ((coordinate - start) as f64 * (cells - 1) as f64 / len as f64).round() as usize
+}
+
+fn put_char(canvas: &mut [Vec<char>], x: usize, y: usize, c: char) {
+    let current = canvas[y][x];
+
+    canvas[y][x] = match (current, c) {
+        ('+', _) | (_, '+') => '+',
+
+        ('-', '|') | ('|', '-') => '+',
+
+        _ => c,
+    };
+}
+
+impl Variant {
+    pub fn zones_for_windows(&self, n: usize) -> Vec<Zone> {
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let mut variant = self.clone();
+
+        variant.take_from_zones();
+
+        while variant.zones.len() < n {
+            variant.extend();
+        }
+
+        return variant.zones[n - 1].clone();
+    }
+
+    pub fn render_ascii(&self, n: usize, cols: usize, rows: usize) -> String {
+        let mut canvas = vec![vec![' '; cols]; rows];
+
+        let zones = self.zones_for_windows(n);
+
+        let rect = match bounding_zone(&self.zones[0]) {
+            Some(val) if cols > 1 && rows > 1 && val.w() > 0 && val.h() > 0 => val,
+
+            _ => return String::new(),
+        };
+
+        for (zone, label) in labelled_zones(&zones) {
+            let left = scale(zone.left, rect.left, rect.w(), cols);
+
+            let right = scale(zone.right, rect.left, rect.w(), cols);
+
+            let top = scale(zone.top, rect.top, rect.h(), rows);
+
+            let bottom = scale(zone.bottom, rect.top, rect.h(), rows);
+
+            for x in left..=right {
+                put_char(&mut canvas, x, top, '-');
+
+                put_char(&mut canvas, x, bottom, '-');
+            }
+
+            for y in top..=bottom {
+                put_char(&mut canvas, left, y, '|');
+
+                put_char(&mut canvas, right, y, '|');
+            }
+
+            for (x, y) in [(left, top), (right, top), (left, bottom), (right, bottom)] {
+                put_char(&mut canvas, x, y, '+');
+            }
+
+            if right - left < 2 || bottom - top < 2 {
+                continue;
+            }
+
+            let width = std::cmp::min(label.len(), right - left - 1);
+
+            let x = (left + right + 1 - width) / 2;
+
+            let y = (top + bottom) / 2;
+
+            for (k, c) in label.chars().take(width).enumerate() {
+                canvas[y][x + k] = c;
+            }
+        }
+
+        let mut ret = String::new();
+
+        for row in canvas {
+            ret.push_str(row.into_iter().collect::<String>().trim_end());
+
+            ret.push('\n');
+        }
+
+        return ret;
+    }
+
+    pub fn render_svg(&self, n: usize) -> String {
+        let zones = self.zones_for_windows(n);
+
+        let rect = match bounding_zone(&self.zones[0]) {
+            Some(val) => val,
+
+            None => return String::new(),
+        };
+
+        let font_size = std::cmp::max(std::cmp::min(rect.w(), rect.h()) / 20, 1);
+
+        let stroke_width = std::cmp::max(font_size / 8, 1);
+
+        let mut ret = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\" width=\"{}\" height=\"{}\">\n",
+            rect.left,
+            rect.top,
+            rect.w(),
+            rect.h(),
+            rect.w(),
+            rect.h()
+        );
+
+        for (zone, label) in labelled_zones(&zones) {
+            ret.push_str(&format!(
+                "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#dde4ee\" stroke=\"#303846\" stroke-width=\"{}\"/>\n",
+                zone.left,
+                zone.top,
+                zone.w(),
+                zone.h(),
+                stroke_width
+            ));
+
+            ret.push_str(&format!(
+                "  <text x=\"{}\" y=\"{}\" font-family=\"sans-serif\" font-size=\"{}\" text-anchor=\"middle\" dominant-baseline=\"middle\">{}</text>\n",
+                zone.left + zone.w() / 2,
+                zone.top + zone.h() / 2,
+                font_size,
+                label
+            ));
+        }
+
+        ret.push_str("</svg>\n");
+
+        return ret;
+    }
+}
@@ -0,0 +1,252 @@
+use crate::*;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Transform {
+    pub mirrored: bool,
+    pub quarter_turns: u32,
+}
+
+impl Zone {
+    pub fn mirrored(&self, axis: Axis, rect: &Zone) -> Self {
+        match axis {
+            Axis::Horizontal => Zone::new(
+                rect.left + rect.right - self.right,
+                self.top,
+                rect.left + rect.right - self.left,
+                self.bottom,
+            ),
+
+            Axis::Vertical => Zone::new(
+                self.left,
+                rect.top + rect.bottom - self.bottom,
+                self.right,
+                rect.top + rect.bottom - self.top,
+            ),
+        }
+    }
+
+    pub fn rotated(&self, rect: &Zone) -> Self {
+        Zone::new(
+            rect.left + rect.bottom - self.bottom,
+            rect.top + self.left - rect.left,
+            rect.left + rect.bottom - self.top,
+            rect.top + self.right - rect.left,
+        )
+    }
+}
+
+impl Transform {
+    pub fn is_identity(&self) -> bool {
+        matches!((self.mirrored, self.quarter_turns % 4), (false, 0))
+    }
+
+    fn mirrored(&self, axis: Axis) -> Self {
+        let quarter_turns = match axis {
+            Axis::Horizontal => 4 - self.quarter_turns % 4,
+
+            Axis::Vertical => 6 - self.quarter_turns % 4,
+        };
+
+        Transform {
+            mirrored: !self.mirrored,
+            quarter_turns: quarter_turns % 4,
+        }
+    }
+
+    fn rotated(&self) -> Self {
+        Transform {
+            mirrored: self.mirrored,
+            quarter_turns: (self.quarter_turns + 1) % 4,
+        }
+    }
+
+    pub fn source_rect(&self, rect: &Zone) -> Zone {
+        match self.quarter_turns % 2 {
+            0 => rect.clone(),
+
+            _ => Zone::new(
+                rect.left,
+                rect.top,
+                rect.left + rect.h(),
+                rect.top + rect.w(),
+            ),
+        }
+    }
+
+    pub fn source_min_size(&self, min_size: &MinSize) -> MinSize {
+        match self.quarter_turns % 2 {
+            0 => min_size.clone(),
+
+            _ => MinSize::new(min_size.height, min_size.width),
+        }
+    }
+
+    pub fn apply(&self, zone: &Zone, source_rect: &Zone) -> Zone {
+        let mut ret = match self.mirrored {
+            true => zone.mirrored(Axis::Horizontal, source_rect),
+
+            false => zone.clone(),
+        };
+
+        let mut rect = source_rect.clone();
+
+        for _ in 0..self.quarter_turns % 4 {
+            ret = ret.rotated(&rect);
+
+            rect = rect.rotated(&rect);
+        }
+
+        return ret;
+    }
+
+    pub fn revert(&self, zone: &Zone, rect: &Zone) -> Zone {
+        let mut ret = zone.clone();
+
+        let mut rect = rect.clone();
+
+        for _ in 0..(4 - self.quarter_turns % 4) % 4 {
+            ret = ret.rotated(&rect);
+
+            rect = rect.rotated(&rect);
+        }
+
+        return match self.mirrored {
+            true => ret.mirrored(Axis::Horizontal, &rect),
+
+            false => ret,
+        };
+    }
+}
+
+impl Variant {
+    pub fn get_transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    pub(crate) fn split_tree_zones(&self, tree: &SplitTree, rect: &Zone) -> Vec<Zone> {
+        let source_rect = self.transform.source_rect(rect);
+
+        return tree
+            .zones(&source_rect, self.auto_split_bias)
+            .iter()
+            .map(|zone| self.transform.apply(zone, &source_rect))
+            .collect();
+    }
+
+    pub(crate) fn extend_transformed(&mut self) {
+        let rect = match bounding_zone(&self.zones[0]) {
+            Some(val) => val,
+
+            None => return,
+        };
+
+        let source_rect = self.transform.source_rect(&rect);
+
+        let mut source = self.clone();
+
+        source.transform = Transform::default();
+
+        source.min_size = self.transform.source_min_size(&self.min_size);
+
+        for min_size in source.zone_min_sizes.values_mut() {
+            *min_size = self.transform.source_min_size(min_size);
+        }
+
+        for zone in source.zones.iter_mut().flatten() {
+            *zone = self.transform.revert(zone, &rect);
+        }
+
+        if let EndTilingBehaviour::Directional {
+            from_zones: Some(from_zones),
+            ..
+        } = &mut source.end_tiling_behaviour
+        {
+            for zone in from_zones.iter_mut() {
+                *zone = self.transform.revert(zone, &rect);
+            }
+        }
+
+        source.extend();
+
+        if let Some(zones) = source.zones.pop() {
+            self.zones.push(
+                zones
+                    .iter()
+                    .map(|zone| self.transform.apply(zone, &source_rect))
+                    .collect(),
+            );
+        }
+    }
+
+    fn transformed(&self, map_zone: impl Fn(&Zone) -> Zone, transform: Transform) -> Variant {
+        let mut ret = self.clone();
+
+        ret.reset_generated_zones();
+
+        for zone in ret.zones.iter_mut().flatten() {
+            *zone = map_zone(zone);
+        }
+
+        if let EndTilingBehaviour::Directional {
+            from_zones: Some(from_zones),
+            ..
+        } = &mut ret.end_tiling_behaviour
+        {
+            for zone in from_zones.iter_mut() {
+                *zone = map_zone(zone);
+            }
+        }
+
+        ret.transform = transform;
+
+        return ret;
+    }
+
+    pub fn mirrored(&self, axis: Axis, monitor_rect: &Zone) -> Variant {
+        self.transformed(
+            |zone| zone.mirrored(axis, monitor_rect),
+            self.transform.mirrored(axis),
+        )
+    }
+
+    pub fn rotated(&self, monitor_rect: &Zone) -> Variant {
+        self.transformed(|zone| zone.rotated(monitor_rect), self.transform.rotated())
+    }
+}
+
+impl Layout {
+    pub fn mirrored(&self, axis: Axis) -> Layout {
+        Layout {
+            variants: self
+                .variants
+                .iter()
+                .map(|variant| variant.mirrored(axis, &self.monitor_rect))
+                .collect(),
+            ..self.clone()
+        }
+    }
+
+    pub fn rotated(&self, quarter_turns: i32) -> Layout {
+        let mut ret = self.clone();
+
+        for _ in 0..quarter_turns.rem_euclid(4) {
+            ret = Layout {
+                monitor_rect: ret.monitor_rect.rotated(&ret.monitor_rect),
+                variants: ret
+                    .variants
+                    .iter()
+                    .map(|variant| variant.rotated(&ret.monitor_rect))
+                    .collect(),
+                ..ret
+            };
+        }
+
+        return ret;
+    }
+}
@@ -0,0 +1,289 @@
+use crate::*;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum SplitTree {
+    Leaf,
+    Split {
+        direction: Direction,
+        ratio: f64,
+        first: Box<SplitTree>,
+        second: Box<SplitTree>,
+    },
+}
+
+impl SplitTree {
+    pub fn split(direction: Direction, ratio: f64, first: SplitTree, second: SplitTree) -> Self {
+        SplitTree::Split {
+            direction,
+            ratio,
+            first: Box::new(first),
+            second: Box::new(second),
+        }
+    }
+
+    pub fn leaves_len(&self) -> usize {
+        match self {
+            SplitTree::Leaf => 1,
+
+            SplitTree::Split { first, second, .. } => first.leaves_len() + second.leaves_len(),
+        }
+    }
+
+    pub fn nodes_len(&self) -> usize {
+        match self {
+            SplitTree::Leaf => 1,
+
+            SplitTree::Split { first, second, .. } => 1 + first.nodes_len() + second.nodes_len(),
+        }
+    }
+
+    pub fn get_node(&self, node: usize) -> Option<&SplitTree> {
+        if node == 0 {
+            return Some(self);
+        }
+
+        match self {
+            SplitTree::Leaf => return None,
+
+            SplitTree::Split { first, second, .. } => {
+                let first_len = first.nodes_len();
+
+                if node <= first_len {
+                    return first.get_node(node - 1);
+                } else {
+                    return second.get_node(node - 1 - first_len);
+                }
+            }
+        }
+    }
+
+    fn get_node_mut(&mut self, node: usize) -> Option<&mut SplitTree> {
+        if node == 0 {
+            return Some(self);
+        }
+
+        match self {
+            SplitTree::Leaf => return None,
+
+            SplitTree::Split { first, second, .. } => {
+                let first_len = first.nodes_len();
+
+                if node <= first_len {
+                    return first.get_node_mut(node - 1);
+                } else {
+                    return second.get_node_mut(node - 1 - first_len);
+                }
+            }
+        }
+    }
+
+    pub fn set_ratio(&mut self, node: usize, val: f64) -> Result<(), LayoutError> {
+        if !(val > 0.0 && val < 1.0) {
+            return Err(LayoutError::InvalidTreeRatio { node, ratio: val });
+        }
+
+        match self.get_node_mut(node) {
+            Some(SplitTree::Split { ratio, .. }) => {
+                *ratio = val;
+
+                return Ok(());
+            }
+
+            _ => return Err(LayoutError::SplitTreeNodeOutOfRange { node }),
+        }
+    }
+
+    pub fn zones(&self, rect: &Zone, auto_split_bias: f64) -> Vec<Zone> {
+        let mut ret = Vec::new();
+
+        self.push_zones(rect, auto_split_bias, &mut ret);
+
+        ret
+    }
+
+    fn push_zones(&self, rect: &Zone, auto_split_bias: f64, zones: &mut Vec<Zone>) {
+        match self {
+            SplitTree::Leaf => zones.push(rect.clone()),
+
+            SplitTree::Split {
+                direction,
+                ratio,
+                first,
+                second,
+            } => {
+                let (first_rect, second_rect) = match direction.resolve(rect, auto_split_bias) {
+                    Direction::Horizontal => {
+                        let at = rect.left + (ratio * rect.w() as f64).round() as i32;
+
+                        (
+                            Zone::new(rect.left, rect.top, at, rect.bottom),
+                            Zone::new(at, rect.top, rect.right, rect.bottom),
+                        )
+                    }
+
+                    Direction::Vertical | Direction::Auto => {
+                        let at = rect.top + (ratio * rect.h() as f64).round() as i32;
+
+                        (
+                            Zone::new(rect.left, rect.top, rect.right, at),
+                            Zone::new(rect.left, at, rect.right, rect.bottom),
+                        )
+                    }
+                };
+
+                first.push_zones(&first_rect, auto_split_bias, zones);
+
+                second.push_zones(&second_rect, auto_split_bias, zones);
+            }
+        }
+    }
+
+    pub(crate) fn validate(&self, node: usize, errors: &mut Vec<LayoutError>) {
+        if let SplitTree::Split {
+            ratio,
+            first,
+            second,
+            ..
+        } = self
+        {
+            if !(*ratio > 0.0 && *ratio < 1.0) {
+                errors.push(LayoutError::InvalidTreeRatio {
+                    node,
+                    ratio: *ratio,
+                });
+            }
+
+            first.validate(node + 1, errors);
+
+            second.validate(node + 1 + first.nodes_len(), errors);
+        }
+    }
+}
+
+pub(crate) fn bounding_zone(zones: &[Zone]) -> Option<Zone> {
+    let first = zones.first()?;
+
+    let mut ret = first.clone();
+
+    for zone in zones.iter().skip(1) {
+        ret.left = std::cmp::min(ret.left, zone.left);
+
+        ret.top = std::cmp::min(ret.top, zone.top);
+
+        ret.right = std::cmp::max(ret.right, zone.right);
+
+        ret.bottom = std::cmp::max(ret.bottom, zone.bottom);
+    }
+
+    return Some(ret);
+}
+
+impl Variant {
+    pub fn from_split_trees(
+        monitor_rect: &Zone,
+        trees: Vec<SplitTree>,
+    ) -> Result<Self, LayoutError> {
+        if trees.is_empty() {
+            return Err(LayoutError::NoZones);
+        }
+
+        let mut zones = Vec::new();
+
+        for (i, tree) in trees.iter().enumerate() {
+            if tree.leaves_len() != i + 1 {
+                return Err(LayoutError::WrongZoneCount {
+                    i,
+                    expected: i + 1,
+                    found: tree.leaves_len(),
+                });
+            }
+
+            let mut errors = Vec::new();
+
+            tree.validate(0, &mut errors);
+
+            if let Some(error) = errors.pop() {
+                return Err(LayoutError::InSplitTree {
+                    i,
+                    error: Box::new(error),
+                });
+            }
+
+            zones.push(tree.zones(monitor_rect, DEFAULT_AUTO_SPLIT_BIAS));
+        }
+
+        let mut ret = Variant::new(monitor_rect.w(), monitor_rect.h());
+
+        ret.manual_zones_until = zones.len();
+
+        ret.zones = zones;
+
+        ret.split_trees = Some(trees.into_iter().map(Some).collect());
+
+        return Ok(ret);
+    }
+
+    pub fn get_split_trees(&self) -> Option<&Vec<Option<SplitTree>>> {
+        self.split_trees.as_ref()
+    }
+
+    pub fn get_split_tree(&self, i: usize) -> Option<&SplitTree> {
+        self.split_trees.as_ref()?.get(i)?.as_ref()
+    }
+
+    pub fn try_set_split_ratio(
+        &mut self,
+        i: usize,
+        node: usize,
+        ratio: f64,
+    ) -> Result<(), LayoutError> {
+        let tree = match self
+            .split_trees
+            .as_mut()
+            .and_then(|trees| trees.get_mut(i))
+            .and_then(Option::as_mut)
+        {
+            Some(val) => val,
+
+            None => return Err(LayoutError::NoSplitTree { i }),
+        };
+
+        tree.set_ratio(node, ratio)
+            .map_err(|error| LayoutError::InSplitTree {
+                i,
+                error: Box::new(error),
+            })?;
+
+        let tree = tree.clone();
+
+        if let Some(rect) = bounding_zone(&self.zones[i]) {
+            self.zones[i] = self.split_tree_zones(&tree, &rect);
+        }
+
+        self.reset_generated_zones();
+
+        return Ok(());
+    }
+
+    pub(crate) fn materialize_split_trees(&mut self, monitor_rect: &Zone) {
+        if let Some(trees) = &self.split_trees {
+            for (i, tree) in trees.iter().enumerate().take(self.zones.len()) {
+                if let Some(tree) = tree {
+                    self.zones[i] = self.split_tree_zones(tree, monitor_rect);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn detach_split_tree(&mut self, i: usize) {
+        if let Some(tree) = self.split_trees.as_mut().and_then(|trees| trees.get_mut(i)) {
+            *tree = None;
+        }
+    }
+
+    pub(crate) fn reset_generated_zones(&mut self) {
+        self.zones.truncate(self.manual_zones_until);
+
+        self.positions.clear();
+    }
+}
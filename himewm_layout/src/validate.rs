@@ -0,0 +1,324 @@
+use crate::*;
+
+fn validate_zones(
+    i: usize,
+    zones: &[Zone],
+    allow_stacked: bool,
+    monitor_rect: &Zone,
+    errors: &mut Vec<LayoutError>,
+) {
+    if zones.len() != i + 1 {
+        errors.push(LayoutError::WrongZoneCount {
+            i,
+            expected: i + 1,
+            found: zones.len(),
+        });
+    }
+
+    let mut area = 0;
+
+    let mut has_invalid_zones = false;
+
+    for (j, zone) in zones.iter().enumerate() {
+        if zone.w() <= 0 || zone.h() <= 0 {
+            errors.push(LayoutError::EmptyZone { i, j });
+
+            has_invalid_zones = true;
+
+            continue;
+        }
+
+        if zone.left < monitor_rect.left
+            || zone.top < monitor_rect.top
+            || zone.right > monitor_rect.right
+            || zone.bottom > monitor_rect.bottom
+        {
+            errors.push(LayoutError::ZoneOutsideMonitor { i, j });
+
+            has_invalid_zones = true;
+        }
+
+        let is_stacked = allow_stacked && zones[..j].contains(zone);
+
+        for (k, other) in zones.iter().enumerate().skip(j + 1) {
+            if allow_stacked && zone == other {
+                continue;
+            }
+
+            if zone.left < other.right
+                && other.left < zone.right
+                && zone.top < other.bottom
+                && other.top < zone.bottom
+            {
+                errors.push(LayoutError::OverlappingZones { i, j, k });
+
+                has_invalid_zones = true;
+            }
+        }
+
+        if !is_stacked {
+            area += zone.w() as i64 * zone.h() as i64;
+        }
+    }
+
+    if !has_invalid_zones && area != monitor_rect.w() as i64 * monitor_rect.h() as i64 {
+        errors.push(LayoutError::Gap { i });
+    }
+}
+
+impl Variant {
+    pub fn validate(&self, monitor_rect: &Zone) -> Result<(), Vec<LayoutError>> {
+        let mut errors = Vec::new();
+
+        if self.zones.is_empty() {
+            return Err(vec![LayoutError::NoZones]);
+        }
+
+        for (i, zones) in self.zones.iter().enumerate() {
+            validate_zones(
+                i,
+                zones,
+                i >= self.manual_zones_until,
+                monitor_rect,
+                &mut errors,
+            );
+        }
+
+        if self.manual_zones_until == 0 || self.manual_zones_until > self.zones.len() {
+            errors.push(LayoutError::ManualZonesUntilOutOfRange {
+                manual_zones_until: self.manual_zones_until,
+                zones_len: self.zones.len(),
+            });
+
+            return Err(errors);
+        }
+
+        if let Some(trees) = &self.split_trees {
+            if trees.len() != self.manual_zones_until {
+                errors.push(LayoutError::SplitTreeCountMismatch {
+                    trees_len: trees.len(),
+                    manual_zones_until: self.manual_zones_until,
+                });
+            }
+
+            for (i, tree) in trees.iter().enumerate() {
+                let tree = match tree {
+                    Some(val) => val,
+
+                    None => continue,
+                };
+
+                let mut tree_errors = Vec::new();
+
+                if tree.leaves_len() != i + 1 {
+                    tree_errors.push(LayoutError::WrongZoneCount {
+                        i,
+                        expected: i + 1,
+                        found: tree.leaves_len(),
+                    });
+                }
+
+                tree.validate(0, &mut tree_errors);
+
+                errors.extend(
+                    tree_errors
+                        .into_iter()
+                        .map(|error| LayoutError::InSplitTree {
+                            i,
+                            error: Box::new(error),
+                        }),
+                );
+            }
+        }
+
+        if self.max_zones == Some(0) {
+            errors.push(LayoutError::NoMaxZones);
+        }
+
+        if self.max_windows == Some(0) {
+            errors.push(LayoutError::NoMaxWindows);
+        }
+
+        if !(self.auto_split_bias > 0.0 && self.auto_split_bias.is_finite()) {
+            errors.push(LayoutError::InvalidAutoSplitBias {
+                bias: self.auto_split_bias,
+            });
+        }
+
+        let end_zones_len = self.zones[self.manual_zones_until - 1].len();
+
+        match &self.end_tiling_behaviour {
+            EndTilingBehaviour::Directional {
+                direction: _,
+                start_from,
+                from_zones,
+                zone_idx,
+            } => match from_zones {
+                _ if *start_from == 0 => {
+                    errors.push(LayoutError::StartFromOutOfRange {
+                        start_from: *start_from,
+                        manual_zones_until: self.manual_zones_until,
+                    });
+                }
+
+                Some(from_zones) if *start_from > 1 => {
+                    validate_zones(
+                        *start_from - 1,
+                        from_zones,
+                        false,
+                        monitor_rect,
+                        &mut errors,
+                    );
+
+                    if *zone_idx >= from_zones.len() {
+                        errors.push(LayoutError::EndZoneIdxOutOfRange {
+                            zone_idx: *zone_idx,
+                            zones_len: from_zones.len(),
+                        });
+                    }
+                }
+
+                None if *start_from > 1 && self.manual_zones_until < 2 => {
+                    errors.push(LayoutError::StartFromOutOfRange {
+                        start_from: *start_from,
+                        manual_zones_until: self.manual_zones_until,
+                    });
+                }
+
+                _ => {
+                    if *zone_idx >= end_zones_len {
+                        errors.push(LayoutError::EndZoneIdxOutOfRange {
+                            zone_idx: *zone_idx,
+                            zones_len: end_zones_len,
+                        });
+                    }
+                }
+            },
+
+            EndTilingBehaviour::Repeating { splits, zone_idx } => {
+                if splits.is_empty() {
+                    errors.push(LayoutError::EmptyRepeatingSplits { i: None });
+                }
+
+                for (i, split_vec) in splits.iter().enumerate() {
+                    if split_vec.is_empty() {
+                        errors.push(LayoutError::EmptyRepeatingSplits { i: Some(i) });
+                    }
+
+                    for (j, split) in split_vec.iter().enumerate() {
+                        if !(split.split_ratio > 0.0 && split.split_ratio < 1.0) {
+                            errors.push(LayoutError::InvalidSplitRatio {
+                                i,
+                                j,
+                                split_ratio: split.split_ratio,
+                            });
+                        }
+
+                        let max_split_idx_offset = match j {
+                            0 => splits.len(),
+                            _ => j,
+                        };
+
+                        if split.split_idx_offset > max_split_idx_offset {
+                            errors.push(LayoutError::SplitIdxOffsetOutOfRange {
+                                i,
+                                j,
+                                split_idx_offset: split.split_idx_offset,
+                            });
+                        }
+                    }
+                }
+
+                if *zone_idx >= end_zones_len {
+                    errors.push(LayoutError::EndZoneIdxOutOfRange {
+                        zone_idx: *zone_idx,
+                        zones_len: end_zones_len,
+                    });
+                }
+            }
+
+            EndTilingBehaviour::Grid { zone_idx, .. }
+            | EndTilingBehaviour::Monocle { zone_idx } => {
+                if *zone_idx >= end_zones_len {
+                    errors.push(LayoutError::EndZoneIdxOutOfRange {
+                        zone_idx: *zone_idx,
+                        zones_len: end_zones_len,
+                    });
+                }
+            }
+
+            EndTilingBehaviour::Spiral {
+                ratio, zone_idx, ..
+            }
+            | EndTilingBehaviour::Dwindle {
+                ratio, zone_idx, ..
+            }
+            | EndTilingBehaviour::MasterStack {
+                master_ratio: ratio,
+                zone_idx,
+                ..
+            }
+            | EndTilingBehaviour::CenteredMaster {
+                center_ratio: ratio,
+                zone_idx,
+            } => {
+                if !(*ratio > 0.0 && *ratio < 1.0) {
+                    errors.push(LayoutError::InvalidEndTilingRatio { ratio: *ratio });
+                }
+
+                if *zone_idx >= end_zones_len {
+                    errors.push(LayoutError::EndZoneIdxOutOfRange {
+                        zone_idx: *zone_idx,
+                        zones_len: end_zones_len,
+                    });
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            return Ok(());
+        } else {
+            return Err(errors);
+        }
+    }
+}
+
+impl Layout {
+    pub fn validate(&self) -> Result<(), Vec<LayoutError>> {
+        let mut errors = Vec::new();
+
+        if self.monitor_rect.w() <= 0 || self.monitor_rect.h() <= 0 {
+            return Err(vec![LayoutError::EmptyMonitorRect]);
+        }
+
+        if self.variants.is_empty() {
+            return Err(vec![LayoutError::NoVariants]);
+        }
+
+        if self.default_variant_idx >= self.variants.len() {
+            errors.push(LayoutError::DefaultVariantOutOfRange {
+                default_variant_idx: self.default_variant_idx,
+                variants_len: self.variants.len(),
+            });
+        }
+
+        for (variant_idx, variant) in self.variants.iter().enumerate() {
+            if let Err(variant_errors) = variant.validate(&self.monitor_rect) {
+                errors.extend(
+                    variant_errors
+                        .into_iter()
+                        .map(|error| LayoutError::InVariant {
+                            variant_idx,
+                            error: Box::new(error),
+                        }),
+                );
+            }
+        }
+
+        if errors.is_empty() {
+            return Ok(());
+        } else {
+            return Err(errors);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+#[test]
+fn auto_direction_follows_monitor_orientation() {
+    let landscape = zone(0, 0, 1920, 1080);
+
+    let portrait = zone(0, 0, 1080, 1920);
+
+    let src = "* => directional(direction=a)";
+
+    assert_eq!(
+        Variant::parse_dsl(src, &landscape)
+            .unwrap()
+            .zones_for_windows(2),
+        vec![zone(0, 0, 960, 1080), zone(960, 0, 1920, 1080)]
+    );
+
+    assert_eq!(
+        Variant::parse_dsl(src, &portrait)
+            .unwrap()
+            .zones_for_windows(2),
+        vec![zone(0, 0, 1080, 960), zone(0, 960, 1080, 1920)]
+    );
+}
+
+#[test]
+fn auto_direction_is_resolved_per_split() {
+    let variant = Variant::parse_dsl("* => dwindle(start=a)", &zone(0, 0, 1920, 1080)).unwrap();
+
+    assert_eq!(
+        variant.zones_for_windows(4),
+        vec![
+            zone(0, 0, 960, 1080),
+            zone(960, 0, 1920, 540),
+            zone(960, 540, 1440, 1080),
+            zone(1440, 540, 1920, 1080),
+        ]
+    );
+}
+
+#[test]
+fn bias_favours_vertical_splits() {
+    let mut variant =
+        Variant::parse_dsl("* ; a[*, *] => auto_split(bias=2)", &zone(0, 0, 1920, 1080)).unwrap();
+
+    assert_eq!(
+        variant.get_zones()[1],
+        vec![zone(0, 0, 1920, 540), zone(0, 540, 1920, 1080)]
+    );
+
+    variant.try_set_auto_split_bias(0.5).unwrap();
+
+    assert_eq!(
+        variant.get_zones()[1],
+        vec![zone(0, 0, 960, 1080), zone(960, 0, 1920, 1080)]
+    );
+
+    assert_eq!(
+        variant.try_set_auto_split_bias(0.0),
+        Err(LayoutError::InvalidAutoSplitBias { bias: 0.0 })
+    );
+}
+
+#[test]
+fn auto_direction_round_trips_through_dsl() {
+    let monitor_rect = zone(0, 0, 1920, 1080);
+
+    let variant = Variant::parse_dsl(
+        "* ; a[0.6 *, *] => spiral(start=a) auto_split(bias=1.5)",
+        &monitor_rect,
+    )
+    .unwrap();
+
+    let dsl = variant.to_dsl().unwrap();
+
+    assert!(dsl.contains("auto_split(bias=1.5)"), "{}", dsl);
+
+    let reparsed = Variant::parse_dsl(&dsl, &monitor_rect).unwrap();
+
+    assert_eq!(reparsed.to_dsl().unwrap(), dsl);
+
+    assert_eq!(reparsed.get_auto_split_bias(), 1.5);
+
+    assert_eq!(reparsed.zones_for_windows(5), variant.zones_for_windows(5));
+}
+
+#[test]
+fn switch_direction_overflow_flips_resolved_auto_direction() {
+    assert_eq!(
+        zones(
+            "* => directional(direction=a) min_size(width=500, overflow=switch_direction)",
+            4
+        ),
+        vec![
+            zone(0, 0, 1920, 270),
+            zone(0, 270, 1920, 540),
+            zone(0, 540, 1920, 810),
+            zone(0, 810, 1920, 1080),
+        ]
+    );
+}
+
+#[test]
+fn switch_direction_overflow_flips_resolved_auto_start_direction() {
+    assert_eq!(
+        zones(
+            "* => dwindle(start=a) min_size(width=1000, overflow=switch_direction)",
+            2
+        ),
+        vec![zone(0, 0, 1920, 540), zone(0, 540, 1920, 1080)]
+    );
+}
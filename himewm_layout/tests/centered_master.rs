@@ -0,0 +1,54 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+#[test]
+fn centre_width_follows_the_ratio() {
+    assert_eq!(
+        zones("* => centered_master()", 3)[0],
+        zone(480, 0, 1440, 1080)
+    );
+
+    assert_eq!(
+        zones("* => centered_master(ratio=0.6)", 3)[0],
+        zone(384, 0, 1536, 1080)
+    );
+
+    let mut variant = Variant::parse_dsl("* => centered_master()", &monitor_rect()).unwrap();
+
+    variant.adjust_master_ratio(0.25);
+
+    assert_eq!(variant.zones_for_windows(3)[0], zone(240, 0, 1680, 1080));
+}
+
+#[test]
+fn master_fills_the_left_side_until_it_has_a_stack() {
+    assert_eq!(
+        zones("* => centered_master()", 2),
+        vec![zone(0, 0, 1440, 1080), zone(1440, 0, 1920, 1080)]
+    );
+}
+
+#[test]
+fn stack_windows_alternate_between_right_and_left() {
+    assert_eq!(
+        zones("* => centered_master()", 5),
+        vec![
+            zone(480, 0, 1440, 1080),
+            zone(1440, 0, 1920, 540),
+            zone(0, 0, 480, 540),
+            zone(1440, 540, 1920, 1080),
+            zone(0, 540, 480, 1080),
+        ]
+    );
+
+    let mut variant = Variant::parse_dsl("* => centered_master()", &monitor_rect()).unwrap();
+
+    while variant.get_zones().len() < 10 {
+        variant.extend();
+    }
+
+    assert_eq!(variant.validate(&monitor_rect()), Ok(()));
+}
@@ -0,0 +1,41 @@
+#![allow(dead_code)]
+
+use himewm_layout::*;
+
+pub fn zone(left: i32, top: i32, right: i32, bottom: i32) -> Zone {
+    Zone {
+        left,
+        top,
+        right,
+        bottom,
+    }
+}
+
+pub fn monitor_rect() -> Zone {
+    zone(0, 0, 1920, 1080)
+}
+
+pub fn no_frame_insets() -> FrameInsets {
+    FrameInsets {
+        left: 0,
+        top: 0,
+        right: 0,
+        bottom: 0,
+    }
+}
+
+pub fn zones(src: &str, n: usize) -> Vec<Zone> {
+    Variant::parse_dsl(src, &monitor_rect())
+        .unwrap()
+        .zones_for_windows(n)
+}
+
+pub fn golden(name: &str) -> Vec<u8> {
+    std::fs::read(
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("golden")
+            .join(format!("{}.json", name)),
+    )
+    .unwrap()
+}
@@ -0,0 +1,250 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+fn dsl_rect() -> Zone {
+    zone(0, 0, 1000, 800)
+}
+
+#[test]
+fn weights_fill_the_remainder() {
+    let variant = Variant::parse_dsl("*; h[0.6 | *]; h[0.6 | v[0.5 / 0.5]]", &dsl_rect()).unwrap();
+
+    assert_eq!(
+        variant.get_zones()[2],
+        vec![
+            zone(0, 0, 600, 800),
+            zone(600, 0, 1000, 400),
+            zone(600, 400, 1000, 800),
+        ]
+    );
+
+    assert_eq!(variant.manual_zones_until(), 3);
+}
+
+#[test]
+fn a_single_arrangement_fills_the_earlier_vectors() {
+    let variant = Variant::parse_dsl("h[0.6 | v[0.5 / 0.5]]", &dsl_rect()).unwrap();
+
+    assert_eq!(
+        variant.get_zones(),
+        Variant::parse_dsl("*; h[0.6 | *]; h[0.6 | v[0.5 / 0.5]]", &dsl_rect())
+            .unwrap()
+            .get_zones()
+    );
+
+    assert_eq!(variant.manual_zones_until(), 3);
+
+    assert_eq!(
+        variant.to_dsl().unwrap(),
+        "*; h[0.6 | *]; h[0.6 | v[0.5 / *]]"
+    );
+}
+
+#[test]
+fn unweighted_children_split_evenly() {
+    let variant = Variant::parse_dsl("*; h[* | *]; h[* | * | *]", &dsl_rect()).unwrap();
+
+    let widths = variant.get_zones()[2]
+        .iter()
+        .map(Zone::w)
+        .collect::<Vec<i32>>();
+
+    assert_eq!(widths, vec![333, 334, 333]);
+}
+
+#[test]
+fn layouts_round_trip_through_the_printer() {
+    let src = "\
+# main layout
+*; h[0.6 | *]; h[0.6 | v[0.5 / 0.5]] => master_stack(count=1, ratio=0.6, stack=v, side=right, zone=1)
+*; v[0.25 / *] => spiral(ratio=0.4, clockwise=false, start=v, zone=0) max_zones(count=5)
+!*; h[2 | 1] => grid(prefer=rows, fill=expand_last, zone=0) min_size(width=200, height=100, overflow=switch_direction) zone_min_size(zone=0, width=300, height=0)
+*
+";
+
+    let layout = Layout::parse_dsl(src, &dsl_rect()).unwrap();
+
+    assert_eq!(layout.variants_len(), 4);
+
+    assert_eq!(layout.default_variant_idx(), 2);
+
+    let printed = layout.to_dsl().unwrap();
+
+    let reparsed = Layout::parse_dsl(&printed, &dsl_rect()).unwrap();
+
+    assert_eq!(reparsed.to_dsl().unwrap(), printed);
+
+    assert_eq!(
+        serde_json::to_value(&reparsed).unwrap(),
+        serde_json::to_value(&layout).unwrap()
+    );
+}
+
+#[test]
+fn layouts_without_split_trees_cannot_be_printed() {
+    let mut layout = Layout::new(1000, 800);
+
+    layout.get_variants_mut()[0].new_zone_vec(1000, 800);
+
+    layout.get_variants_mut()[0].split(1, 0, SplitDirection::Horizontal(500));
+
+    assert_eq!(layout.to_dsl(), None);
+}
+
+#[test]
+fn errors_point_at_the_offending_token() {
+    let error = |src: &str| Layout::parse_dsl(src, &dsl_rect()).unwrap_err();
+
+    assert_eq!(
+        error("*\n*; h[0.5 / *]"),
+        DslError {
+            line: 2,
+            column: 10,
+            message: String::from("expected '|' or ']', found '/'"),
+        }
+    );
+
+    assert_eq!(
+        error("*; h[* | *]; h[* | *]"),
+        DslError {
+            line: 1,
+            column: 14,
+            message: String::from("arrangement 3 has 2 zones, expected 3"),
+        }
+    );
+
+    assert_eq!(
+        error("*; h[0.7 | 0.5 | *]"),
+        DslError {
+            line: 1,
+            column: 4,
+            message: String::from("weights add up to 1.2, leaving no room for unweighted children"),
+        }
+    );
+
+    assert_eq!(
+        error("* => spiral(ratio=1.5)"),
+        DslError {
+            line: 1,
+            column: 19,
+            message: String::from("'ratio' expects a ratio between 0 and 1, found '1.5'"),
+        }
+    );
+
+    assert_eq!(
+        error("* => monocle(count=2)"),
+        DslError {
+            line: 1,
+            column: 14,
+            message: String::from("'monocle' does not take 'count'"),
+        }
+    );
+
+    assert_eq!(
+        error("*; h[* | *] => monocle(zone=4)"),
+        DslError {
+            line: 1,
+            column: 16,
+            message: LayoutError::EndZoneIdxOutOfRange {
+                zone_idx: 4,
+                zones_len: 2,
+            }
+            .to_string(),
+        }
+    );
+}
+
+#[test]
+fn metadata_round_trips_through_the_printer() {
+    let src = "\
+layout(name=\"coding\", description=\"editor \\\"left\\\"\", tag=work, tag=\"two words\")
+*; h[0.6 | *] => meta(name=\"wide-left\") spiral(ratio=0.5, clockwise=true, start=h, zone=0)
+";
+
+    let layout = Layout::parse_dsl(src, &dsl_rect()).unwrap();
+
+    assert_eq!(layout.get_name(), "coding");
+
+    assert_eq!(layout.get_metadata().description, "editor \"left\"");
+
+    assert!(layout.get_metadata().has_tag("two words"));
+
+    assert_eq!(layout.get_variants()[0].get_name(), "wide-left");
+
+    let printed = layout.to_dsl().unwrap();
+
+    assert_eq!(
+        Layout::parse_dsl(&printed, &dsl_rect())
+            .unwrap()
+            .get_metadata(),
+        layout.get_metadata()
+    );
+
+    assert_eq!(
+        Layout::parse_dsl("layout(name=\"a\")\nlayout(name=\"b\")\n*", &dsl_rect())
+            .unwrap_err()
+            .line,
+        2
+    );
+}
+
+#[test]
+fn repeating_splits_round_trip_through_the_printer() {
+    let src = "*; h[0.5 | *] => repeating(zone=1) repeat_split(step=0, direction=v, ratio=0.5, offset=0, swap=false) repeat_split(step=1, direction=h, ratio=0.4, offset=0, swap=true) repeat_split(step=1, direction=v, ratio=0.5, offset=1, swap=false)";
+
+    let variant = Variant::parse_dsl(src, &dsl_rect()).unwrap();
+
+    let mut expected = Variant::parse_dsl("*; h[0.5 | *]", &dsl_rect()).unwrap();
+
+    expected.set_end_tiling_behaviour(EndTilingBehaviour::Repeating {
+        splits: vec![
+            vec![RepeatingSplit::new(Direction::Vertical, 0.5, 0, false)],
+            vec![
+                RepeatingSplit::new(Direction::Horizontal, 0.4, 0, true),
+                RepeatingSplit::new(Direction::Vertical, 0.5, 1, false),
+            ],
+        ],
+        zone_idx: 1,
+    });
+
+    assert_eq!(
+        serde_json::to_value(&variant).unwrap(),
+        serde_json::to_value(&expected).unwrap()
+    );
+
+    assert_eq!(variant.zones_for_windows(5), expected.zones_for_windows(5));
+
+    assert_eq!(variant.to_dsl().unwrap(), src);
+
+    let error = |src: &str| Variant::parse_dsl(src, &dsl_rect()).unwrap_err();
+
+    assert_eq!(
+        error("* => repeat_split(step=0)"),
+        DslError {
+            line: 1,
+            column: 6,
+            message: String::from("'repeat_split' needs a 'repeating' clause before it"),
+        }
+    );
+
+    assert_eq!(
+        error("* => repeating() repeat_split(step=1)"),
+        DslError {
+            line: 1,
+            column: 36,
+            message: String::from("step 1 skips step 0"),
+        }
+    );
+
+    assert_eq!(
+        error("* => repeating()"),
+        DslError {
+            line: 1,
+            column: 6,
+            message: LayoutError::EmptyRepeatingSplits { i: None }.to_string(),
+        }
+    );
+}
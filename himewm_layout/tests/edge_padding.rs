@@ -0,0 +1,99 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+fn side_padding() -> EdgePadding {
+    EdgePadding {
+        left: 10,
+        top: 20,
+        right: 30,
+        bottom: 40,
+    }
+}
+
+#[test]
+fn edge_padding_only_applies_to_monitor_edges() {
+    let mut layout = Layout::parse_dsl("* ; h[0.5 | *]", &monitor_rect()).unwrap();
+
+    layout.update_all(
+        8,
+        &side_padding(),
+        &no_frame_insets(),
+        false,
+        &monitor_rect(),
+    );
+
+    assert_eq!(
+        layout.get_variants()[0].get_positions_at(1),
+        &vec![
+            Position {
+                x: 10,
+                y: 20,
+                cx: 942,
+                cy: 1020,
+            },
+            Position {
+                x: 968,
+                y: 20,
+                cx: 922,
+                cy: 1020,
+            },
+        ]
+    );
+}
+
+#[test]
+fn struts_shrink_the_work_area() {
+    let work_area = monitor_rect().shrunk(&EdgePadding {
+        left: 0,
+        top: 40,
+        right: 100,
+        bottom: 0,
+    });
+
+    assert_eq!(work_area, zone(0, 40, 1820, 1080));
+
+    let mut layout = Layout::parse_dsl("* ; h[0.5 | *]", &monitor_rect())
+        .unwrap()
+        .scaled_to(&work_area);
+
+    layout.update_all(
+        0,
+        &EdgePadding::default(),
+        &no_frame_insets(),
+        false,
+        &work_area,
+    );
+
+    assert_eq!(
+        layout.get_variants()[0].get_positions_at(1),
+        &vec![
+            Position {
+                x: 0,
+                y: 40,
+                cx: 910,
+                cy: 1040,
+            },
+            Position {
+                x: 910,
+                y: 40,
+                cx: 910,
+                cy: 1040,
+            },
+        ]
+    );
+
+    layout.update_all(0, &side_padding(), &no_frame_insets(), false, &work_area);
+
+    assert_eq!(
+        layout.get_variants()[0].get_positions_at(0)[0],
+        Position {
+            x: 10,
+            y: 60,
+            cx: 1780,
+            cy: 980,
+        }
+    );
+}
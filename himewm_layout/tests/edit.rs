@@ -0,0 +1,193 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+fn two_column_variant() -> Variant {
+    let mut variant = Variant::new(1920, 1080);
+
+    variant.new_zone_vec(1920, 1080);
+
+    variant.split(1, 0, SplitDirection::Horizontal(960));
+
+    variant
+}
+
+#[test]
+fn out_of_range_indices_are_rejected() {
+    let mut variant = two_column_variant();
+
+    assert_eq!(
+        variant.try_split(2, 0, SplitDirection::Horizontal(100)),
+        Err(LayoutError::ZoneVecOutOfRange { i: 2, zones_len: 2 })
+    );
+
+    assert_eq!(
+        variant.try_swap_zones(1, 0, 2),
+        Err(LayoutError::ZoneOutOfRange {
+            i: 1,
+            j: 2,
+            zones_len: 2
+        })
+    );
+
+    assert_eq!(
+        variant.try_delete_zones(2),
+        Err(LayoutError::ZoneVecOutOfRange { i: 2, zones_len: 2 })
+    );
+
+    assert_eq!(variant.get_zones().len(), 2);
+}
+
+#[test]
+fn invalid_edits_leave_the_variant_unchanged() {
+    let mut variant = two_column_variant();
+
+    let zones = variant.get_zones().clone();
+
+    assert_eq!(
+        variant.try_split(1, 0, SplitDirection::Horizontal(1200)),
+        Err(LayoutError::SplitOutsideZone {
+            i: 1,
+            j: 0,
+            at: 1200
+        })
+    );
+
+    variant
+        .try_split(1, 1, SplitDirection::Vertical(540))
+        .unwrap();
+
+    assert_eq!(
+        variant.try_merge_zones(1, 0, 2),
+        Err(LayoutError::ZonesNotAdjacent { i: 1, j: 0, k: 2 })
+    );
+
+    variant.try_delete_zones(1).unwrap();
+
+    assert_eq!(variant.try_delete_zones(0), Err(LayoutError::LastZoneVec));
+
+    assert_eq!(variant.get_zones()[0], zones[0]);
+}
+
+#[test]
+fn end_tiling_setters_check_the_behaviour() {
+    let mut variant = two_column_variant();
+
+    assert_eq!(
+        variant.try_set_end_tiling_ratio(0.3),
+        Err(LayoutError::EndTilingBehaviourMismatch)
+    );
+
+    assert_eq!(
+        variant.try_set_end_tiling_start_from(3),
+        Err(LayoutError::StartFromOutOfRange {
+            start_from: 3,
+            manual_zones_until: 2
+        })
+    );
+
+    variant.set_end_tiling_behaviour(EndTilingBehaviour::default_dwindle());
+
+    assert_eq!(
+        variant.try_set_end_tiling_ratio(1.0),
+        Err(LayoutError::InvalidEndTilingRatio { ratio: 1.0 })
+    );
+
+    assert_eq!(variant.try_set_end_tiling_ratio(0.3), Ok(()));
+}
+
+#[test]
+fn repeating_split_errors_name_the_split() {
+    let mut variant = two_column_variant();
+
+    variant.set_end_tiling_behaviour(EndTilingBehaviour::default_repeating());
+
+    variant
+        .try_add_repeating_split(Direction::Horizontal, 0.5, 0, false)
+        .unwrap();
+
+    variant
+        .try_add_repeating_split(Direction::Vertical, 0.5, 0, false)
+        .unwrap();
+
+    assert_eq!(
+        variant.try_add_repeating_split(Direction::Horizontal, 1.5, 0, false),
+        Err(LayoutError::InvalidSplitRatio {
+            i: 2,
+            j: 2,
+            split_ratio: 1.5
+        })
+    );
+
+    assert_eq!(
+        variant.try_set_repeating_split_ratio(1, 1, 0.0),
+        Err(LayoutError::InvalidSplitRatio {
+            i: 1,
+            j: 1,
+            split_ratio: 0.0
+        })
+    );
+
+    assert_eq!(
+        variant.try_remove_repeating_split(1, 2),
+        Err(LayoutError::RepeatingSplitOutOfRange { i: 1, j: 2 })
+    );
+}
+
+#[test]
+fn repeating_split_edits_reset_generated_zones() {
+    let mut variant = two_column_variant();
+
+    variant.set_end_tiling_behaviour(EndTilingBehaviour::default_repeating());
+
+    variant
+        .try_add_repeating_split(Direction::Vertical, 0.5, 0, false)
+        .unwrap();
+
+    variant.extend();
+
+    assert_eq!(variant.get_zones().len(), 3);
+
+    variant
+        .try_set_repeating_split_direction(0, 0, Direction::Horizontal)
+        .unwrap();
+
+    assert_eq!(variant.get_zones().len(), 2);
+
+    variant.extend();
+
+    variant.try_set_repeating_split_ratio(0, 0, 0.4).unwrap();
+
+    assert_eq!(variant.get_zones().len(), 2);
+
+    variant.extend();
+
+    variant.try_set_repeating_split_idx_offset(0, 0, 1).unwrap();
+
+    assert_eq!(variant.get_zones().len(), 2);
+
+    variant.extend();
+
+    variant.try_set_repeating_split_swap(0, 0, true).unwrap();
+
+    assert_eq!(variant.get_zones().len(), 2);
+}
+
+#[test]
+fn layouts_keep_at_least_one_variant() {
+    let mut layout = Layout::new(1920, 1080);
+
+    assert_eq!(layout.try_delete_variant(0), Err(LayoutError::LastVariant));
+
+    assert_eq!(
+        layout.try_swap_variants(0, 1),
+        Err(LayoutError::VariantOutOfRange {
+            variant_idx: 1,
+            variants_len: 1
+        })
+    );
+
+    assert_eq!(layout.get_monitor_rect(), &monitor_rect());
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+fn custom_frame_insets() -> FrameInsets {
+    FrameInsets {
+        left: 2,
+        top: 3,
+        right: 4,
+        bottom: 5,
+    }
+}
+
+fn lone_position(frame_insets: &FrameInsets) -> Position {
+    let mut variant = Variant::new(1920, 1080);
+
+    variant.update(
+        8,
+        &EdgePadding::default(),
+        frame_insets,
+        false,
+        &monitor_rect(),
+    );
+
+    variant.get_positions_at(0)[0].clone()
+}
+
+#[test]
+fn update_offsets_positions_by_the_frame_insets() {
+    assert_eq!(
+        lone_position(&FrameInsets::default()),
+        Position {
+            x: -7,
+            y: 0,
+            cx: 1934,
+            cy: 1087,
+        }
+    );
+
+    assert_eq!(
+        lone_position(&custom_frame_insets()),
+        Position {
+            x: -2,
+            y: -3,
+            cx: 1926,
+            cy: 1088,
+        }
+    );
+}
+
+#[test]
+fn with_frame_insets_converts_between_insets() {
+    let position = lone_position(&FrameInsets::default());
+
+    assert_eq!(
+        position.with_frame_insets(&FrameInsets::default(), &custom_frame_insets()),
+        lone_position(&custom_frame_insets())
+    );
+
+    assert_eq!(
+        position
+            .with_frame_insets(&FrameInsets::default(), &custom_frame_insets())
+            .with_frame_insets(&custom_frame_insets(), &FrameInsets::default()),
+        position
+    );
+
+    assert_eq!(
+        position.with_frame_insets(&FrameInsets::default(), &FrameInsets::default()),
+        position
+    );
+}
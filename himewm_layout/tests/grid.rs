@@ -0,0 +1,79 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+#[test]
+fn grid_fills_rows_and_stretches_the_last_one() {
+    assert_eq!(
+        zones("* => grid()", 3),
+        vec![
+            zone(0, 0, 960, 540),
+            zone(960, 0, 1920, 540),
+            zone(0, 540, 1920, 1080),
+        ]
+    );
+
+    assert_eq!(
+        zones("* => grid()", 5),
+        vec![
+            zone(0, 0, 640, 540),
+            zone(640, 0, 1280, 540),
+            zone(1280, 0, 1920, 540),
+            zone(0, 540, 960, 1080),
+            zone(960, 540, 1920, 1080),
+        ]
+    );
+}
+
+#[test]
+fn grid_can_prefer_rows() {
+    assert_eq!(
+        zones("* => grid(prefer=rows)", 5),
+        vec![
+            zone(0, 0, 960, 360),
+            zone(0, 360, 960, 720),
+            zone(0, 720, 960, 1080),
+            zone(960, 0, 1920, 540),
+            zone(960, 540, 1920, 1080),
+        ]
+    );
+}
+
+#[test]
+fn expand_last_keeps_cells_aligned() {
+    assert_eq!(
+        zones("* => grid(fill=expand_last)", 5),
+        vec![
+            zone(0, 0, 640, 540),
+            zone(640, 0, 1280, 540),
+            zone(1280, 0, 1920, 540),
+            zone(0, 540, 640, 1080),
+            zone(640, 540, 1920, 1080),
+        ]
+    );
+
+    assert_eq!(
+        zones("* => grid(fill=expand_last)", 4),
+        zones("* => grid()", 4)
+    );
+}
+
+#[test]
+fn grids_tile_without_gaps() {
+    for src in [
+        "* => grid()",
+        "* => grid(prefer=rows)",
+        "* => grid(fill=expand_last)",
+        "*; h[0.3 | *] => grid(zone=1)",
+    ] {
+        let mut variant = Variant::parse_dsl(src, &monitor_rect()).unwrap();
+
+        while variant.get_zones().len() < 12 {
+            variant.extend();
+        }
+
+        assert_eq!(variant.validate(&monitor_rect()), Ok(()), "{}", src);
+    }
+}
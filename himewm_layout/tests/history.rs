@@ -0,0 +1,239 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+fn json(layout: &Layout) -> serde_json::Value {
+    serde_json::to_value(layout).unwrap()
+}
+
+fn tree_layout() -> Layout {
+    Layout::parse_dsl("*; h[0.25 | *]\n* => monocle()", &monitor_rect()).unwrap()
+}
+
+#[test]
+fn undo_and_redo_step_through_each_edit() {
+    let mut editor = LayoutEditor::new(Layout::new(1920, 1080));
+
+    let edits = vec![
+        LayoutEdit::NewZoneVec { variant_idx: 0 },
+        LayoutEdit::Split {
+            variant_idx: 0,
+            i: 1,
+            j: 0,
+            direction: SplitDirection::Horizontal(960),
+        },
+        LayoutEdit::SetMaxZones {
+            variant_idx: 0,
+            max_zones: Some(3),
+        },
+        LayoutEdit::NewVariant,
+        LayoutEdit::SetDefaultVariantIdx { variant_idx: 1 },
+    ];
+
+    let mut states = vec![json(editor.get_layout())];
+
+    for edit in edits {
+        editor.apply(edit).unwrap();
+
+        states.push(json(editor.get_layout()));
+    }
+
+    for state in states.iter().rev().skip(1) {
+        assert!(editor.undo());
+
+        assert_eq!(&json(editor.get_layout()), state);
+    }
+
+    assert!(!editor.undo());
+
+    for state in states.iter().skip(1) {
+        assert!(editor.redo());
+
+        assert_eq!(&json(editor.get_layout()), state);
+    }
+
+    assert!(!editor.redo());
+}
+
+#[test]
+fn undo_restores_deleted_data() {
+    let mut editor = LayoutEditor::new(tree_layout());
+
+    let before = json(editor.get_layout());
+
+    editor
+        .apply(LayoutEdit::SetSplitRatio {
+            variant_idx: 0,
+            i: 1,
+            node: 0,
+            ratio: 0.5,
+        })
+        .unwrap();
+
+    editor
+        .apply(LayoutEdit::MergeZones {
+            variant_idx: 0,
+            i: 1,
+            j: 0,
+            k: 1,
+        })
+        .unwrap();
+
+    editor
+        .apply(LayoutEdit::DeleteZones {
+            variant_idx: 0,
+            i: 1,
+        })
+        .unwrap();
+
+    editor
+        .apply(LayoutEdit::DeleteVariant { variant_idx: 0 })
+        .unwrap();
+
+    assert_eq!(editor.get_layout().variants_len(), 1);
+
+    while editor.undo() {}
+
+    assert_eq!(json(editor.get_layout()), before);
+
+    assert_eq!(
+        editor.get_layout().get_variants()[0].get_split_tree(1),
+        tree_layout().get_variants()[0].get_split_tree(1)
+    );
+}
+
+#[test]
+fn groups_undo_as_one_step() {
+    let mut editor = LayoutEditor::new(tree_layout());
+
+    let before = json(editor.get_layout());
+
+    editor.begin_group();
+
+    editor.apply(LayoutEdit::NewVariant).unwrap();
+
+    editor.begin_group();
+
+    editor
+        .apply(LayoutEdit::SwapVariants { i: 0, j: 2 })
+        .unwrap();
+
+    editor.end_group();
+
+    editor
+        .apply(LayoutEdit::SetMaxWindows {
+            variant_idx: 1,
+            max_windows: Some(2),
+        })
+        .unwrap();
+
+    editor.end_group();
+
+    let after = json(editor.get_layout());
+
+    assert!(editor.undo());
+
+    assert_eq!(json(editor.get_layout()), before);
+
+    assert!(!editor.can_undo());
+
+    assert!(editor.redo());
+
+    assert_eq!(json(editor.get_layout()), after);
+}
+
+#[test]
+fn cancel_group_restores_the_layout() {
+    let mut editor = LayoutEditor::new(tree_layout());
+
+    editor
+        .apply(LayoutEdit::SetAutoSplitBias {
+            variant_idx: 0,
+            bias: 2.0,
+        })
+        .unwrap();
+
+    let before = json(editor.get_layout());
+
+    editor.begin_group();
+
+    editor
+        .apply(LayoutEdit::Split {
+            variant_idx: 0,
+            i: 1,
+            j: 1,
+            direction: SplitDirection::Vertical(540),
+        })
+        .unwrap();
+
+    editor
+        .apply(LayoutEdit::DeleteVariant { variant_idx: 1 })
+        .unwrap();
+
+    editor.cancel_group();
+
+    assert_eq!(json(editor.get_layout()), before);
+
+    assert!(editor.undo());
+
+    assert!(!editor.can_undo());
+}
+
+#[test]
+fn failed_steps_roll_the_whole_group_back() {
+    let mut editor = LayoutEditor::new(tree_layout());
+
+    let before = json(editor.get_layout());
+
+    editor.begin_group();
+
+    editor
+        .apply(LayoutEdit::SetMaxZones {
+            variant_idx: 0,
+            max_zones: Some(2),
+        })
+        .unwrap();
+
+    assert_eq!(
+        editor.apply(LayoutEdit::DeleteVariant { variant_idx: 5 }),
+        Err(LayoutError::VariantOutOfRange {
+            variant_idx: 5,
+            variants_len: 2,
+        })
+    );
+
+    assert_eq!(json(editor.get_layout()), before);
+
+    assert!(!editor.can_undo());
+
+    editor.apply(LayoutEdit::NewVariant).unwrap();
+
+    editor.end_group();
+
+    assert!(editor.undo());
+
+    assert_eq!(json(editor.get_layout()), before);
+}
+
+#[test]
+fn new_edits_clear_redo() {
+    let mut editor = LayoutEditor::new(tree_layout());
+
+    editor.apply(LayoutEdit::NewVariant).unwrap();
+
+    assert!(editor.undo());
+
+    assert!(editor.can_redo());
+
+    editor
+        .apply(LayoutEdit::SetDefaultVariantIdx { variant_idx: 1 })
+        .unwrap();
+
+    assert!(!editor.can_redo());
+
+    assert!(!editor.redo());
+
+    assert_eq!(editor.get_layout().variants_len(), 2);
+}
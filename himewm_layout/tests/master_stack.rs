@@ -0,0 +1,123 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+fn extended(src: &str, n: usize) -> Variant {
+    let mut variant = Variant::parse_dsl(src, &monitor_rect()).unwrap();
+
+    while variant.get_zones().len() < n {
+        variant.extend();
+    }
+
+    variant
+}
+
+#[test]
+fn master_ratio_adjustments_clamp_and_regenerate() {
+    let mut variant = extended("* => master_stack()", 3);
+
+    variant.adjust_master_ratio(0.25);
+
+    assert_eq!(variant.get_zones().len(), 1);
+
+    assert_eq!(
+        variant.zones_for_windows(3),
+        vec![
+            zone(0, 0, 1440, 1080),
+            zone(1440, 0, 1920, 540),
+            zone(1440, 540, 1920, 1080),
+        ]
+    );
+
+    variant.adjust_master_ratio(1.0);
+
+    assert_eq!(
+        variant.zones_for_windows(2),
+        vec![zone(0, 0, 1824, 1080), zone(1824, 0, 1920, 1080)]
+    );
+
+    variant.adjust_master_ratio(-2.0);
+
+    assert_eq!(
+        variant.zones_for_windows(2),
+        vec![zone(0, 0, 96, 1080), zone(96, 0, 1920, 1080)]
+    );
+}
+
+#[test]
+fn master_count_moves_windows_between_master_and_stack() {
+    let mut variant = extended("* => master_stack()", 4);
+
+    variant.set_master_count(2);
+
+    assert_eq!(variant.get_zones().len(), 1);
+
+    assert_eq!(
+        variant.zones_for_windows(4),
+        vec![
+            zone(0, 0, 960, 540),
+            zone(0, 540, 960, 1080),
+            zone(960, 0, 1920, 540),
+            zone(960, 540, 1920, 1080),
+        ]
+    );
+
+    variant.adjust_master_count(-5);
+
+    assert_eq!(
+        variant.zones_for_windows(2),
+        vec![zone(0, 0, 1920, 540), zone(0, 540, 1920, 1080)]
+    );
+
+    variant.adjust_master_count(1);
+
+    assert_eq!(
+        variant.zones_for_windows(2),
+        vec![zone(0, 0, 960, 1080), zone(960, 0, 1920, 1080)]
+    );
+
+    assert_eq!(
+        extended("* => grid()", 1).try_set_master_count(2),
+        Err(LayoutError::EndTilingBehaviourMismatch)
+    );
+}
+
+#[test]
+fn end_tiling_setters_drop_generated_zones() {
+    let mut variant = extended("* => master_stack()", 3);
+
+    variant.set_end_tiling_ratio(0.25);
+
+    assert_eq!(variant.get_zones().len(), 1);
+
+    variant.extend();
+
+    assert_eq!(
+        variant.get_zones()[1],
+        vec![zone(0, 0, 480, 1080), zone(480, 0, 1920, 1080)]
+    );
+
+    variant.set_end_tiling_direction(Direction::Horizontal);
+
+    assert_eq!(variant.get_zones().len(), 1);
+
+    let mut variant = extended("* => grid()", 3);
+
+    variant.set_grid_preference(GridPreference::Rows);
+
+    assert_eq!(variant.get_zones().len(), 1);
+
+    let mut variant = extended("* => grid()", 3);
+
+    variant.set_grid_fill(GridFill::ExpandLast);
+
+    assert_eq!(variant.get_zones().len(), 1);
+
+    let mut variant = extended("* => spiral()", 3);
+
+    variant.set_end_tiling_clockwise(false);
+
+    assert_eq!(variant.get_zones().len(), 1);
+}
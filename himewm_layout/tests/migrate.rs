@@ -0,0 +1,158 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+fn to_value(layout: &Layout) -> serde_json::Value {
+    serde_json::from_slice(&layout.to_json().unwrap()).unwrap()
+}
+
+fn golden_value(name: &str) -> serde_json::Value {
+    serde_json::from_slice(&golden(name)).unwrap()
+}
+
+#[test]
+fn old_layouts_migrate_to_current_version() {
+    for (old, current) in [
+        ("v0_directional", "v1_directional"),
+        ("v0_repeating", "v1_repeating"),
+    ] {
+        let (layout, migrated) = Layout::from_json(&golden(old)).unwrap();
+
+        assert!(migrated, "{}", old);
+
+        assert_eq!(layout.version(), LAYOUT_VERSION);
+
+        assert_eq!(layout.validate(), Ok(()), "{}", old);
+
+        assert_eq!(to_value(&layout), golden_value(current), "{}", old);
+    }
+}
+
+#[test]
+fn current_layouts_load_without_migration() {
+    for name in ["v1_directional", "v1_repeating"] {
+        let (layout, migrated) = Layout::from_json(&golden(name)).unwrap();
+
+        assert!(!migrated, "{}", name);
+
+        assert_eq!(layout.validate(), Ok(()), "{}", name);
+
+        assert_eq!(to_value(&layout), golden_value(name), "{}", name);
+    }
+}
+
+#[test]
+fn migrated_layouts_still_tile() {
+    let (mut layout, _) = Layout::from_json(&golden("v0_directional")).unwrap();
+
+    let monitor_rect = layout.get_monitor_rect().clone();
+
+    layout.update_all(
+        0,
+        &EdgePadding::default(),
+        &FrameInsets::default(),
+        false,
+        &monitor_rect,
+    );
+
+    let variant = &mut layout.get_variants_mut()[0];
+
+    while variant.positions_len() < 4 {
+        variant.extend();
+
+        variant.update(
+            0,
+            &EdgePadding::default(),
+            &FrameInsets::default(),
+            false,
+            &monitor_rect,
+        );
+    }
+
+    assert_eq!(variant.get_positions_at(3).len(), 4);
+}
+
+#[test]
+fn missing_fields_fall_back_to_defaults() {
+    for name in ["v1_directional", "v1_repeating"] {
+        let mut value = golden_value(name);
+
+        for key in ["name", "description", "tags"] {
+            value.as_object_mut().unwrap().remove(key).unwrap();
+        }
+
+        for variant in value["variants"].as_array_mut().unwrap() {
+            for key in [
+                "name",
+                "description",
+                "tags",
+                "max_windows",
+                "overflow_policy",
+                "auto_split_bias",
+            ] {
+                variant.as_object_mut().unwrap().remove(key).unwrap();
+            }
+        }
+
+        let (layout, migrated) = Layout::from_json(value.to_string().as_bytes()).unwrap();
+
+        assert!(!migrated, "{}", name);
+
+        assert_eq!(to_value(&layout), golden_value(name), "{}", name);
+    }
+}
+
+#[test]
+fn newer_versions_are_rejected() {
+    let mut value = golden_value("v1_directional");
+
+    value["version"] = serde_json::Value::from(LAYOUT_VERSION + 1);
+
+    assert_eq!(
+        Layout::from_json(value.to_string().as_bytes()).unwrap_err(),
+        LayoutError::UnsupportedVersion {
+            version: LAYOUT_VERSION as u64 + 1
+        }
+    );
+}
+
+#[test]
+fn malformed_json_is_reported() {
+    assert!(matches!(
+        Layout::from_json(b"{ \"variants\": "),
+        Err(LayoutError::InvalidJson { .. })
+    ));
+}
+
+#[test]
+fn metadata_survives_a_round_trip() {
+    let mut layout = Layout::new(1920, 1080);
+
+    layout.set_metadata(Metadata {
+        name: String::from("coding"),
+        description: String::from("editor on the left"),
+        tags: vec![String::from("work")],
+    });
+
+    layout.get_variants_mut()[0].set_name("wide-left");
+
+    let (loaded, migrated) = Layout::from_json(&layout.to_json().unwrap()).unwrap();
+
+    assert!(!migrated);
+
+    assert_eq!(loaded.get_metadata(), layout.get_metadata());
+
+    assert_eq!(loaded.find_variant("wide-left"), Some(0));
+
+    assert_eq!(
+        loaded.variant_label(0),
+        "Layout 'coding' / variant 'wide-left'"
+    );
+
+    assert_eq!(
+        Layout::new(1920, 1080).variant_label(0),
+        "unnamed layout / variant 0"
+    );
+}
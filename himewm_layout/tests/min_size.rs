@@ -0,0 +1,89 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+#[test]
+fn stack_overflow_stacks_onto_the_last_zone() {
+    let src = "* => directional(direction=h) min_size(width=600, height=100)";
+
+    assert_eq!(
+        zones(src, 3),
+        vec![
+            zone(0, 0, 640, 1080),
+            zone(640, 0, 1280, 1080),
+            zone(1280, 0, 1920, 1080),
+        ]
+    );
+
+    assert_eq!(
+        zones(src, 5),
+        vec![
+            zone(0, 0, 640, 1080),
+            zone(640, 0, 1280, 1080),
+            zone(1280, 0, 1920, 1080),
+            zone(1280, 0, 1920, 1080),
+            zone(1280, 0, 1920, 1080),
+        ]
+    );
+}
+
+#[test]
+fn switch_direction_overflow_tries_the_other_direction() {
+    assert_eq!(
+        zones(
+            "* => directional(direction=h) min_size(width=600, height=100, overflow=switch_direction)",
+            4
+        ),
+        vec![
+            zone(0, 0, 1920, 270),
+            zone(0, 270, 1920, 540),
+            zone(0, 540, 1920, 810),
+            zone(0, 810, 1920, 1080),
+        ]
+    );
+}
+
+#[test]
+fn switch_direction_overflow_stacks_when_neither_direction_fits() {
+    assert_eq!(
+        zones(
+            "* => dwindle() min_size(width=600, height=300, overflow=switch_direction)",
+            4
+        ),
+        vec![
+            zone(0, 0, 960, 1080),
+            zone(960, 0, 1920, 540),
+            zone(960, 540, 1920, 1080),
+            zone(960, 540, 1920, 1080),
+        ]
+    );
+}
+
+#[test]
+fn zone_min_sizes_override_the_variant_minimum() {
+    assert_eq!(
+        zones(
+            "* => directional(direction=h) zone_min_size(zone=0, width=700, height=0)",
+            3
+        ),
+        vec![
+            zone(0, 0, 960, 1080),
+            zone(960, 0, 1920, 1080),
+            zone(960, 0, 1920, 1080),
+        ]
+    );
+
+    let mut variant = Variant::parse_dsl(
+        "* => directional(direction=h) min_size(width=600, height=0)",
+        &monitor_rect(),
+    )
+    .unwrap();
+
+    variant.set_zone_min_size(3, Some(MinSize::new(0, 0)));
+
+    assert_eq!(variant.min_size_at(3), &MinSize::new(0, 0));
+
+    assert_eq!(variant.min_size_at(2), &MinSize::new(600, 0));
+}
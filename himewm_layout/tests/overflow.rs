@@ -0,0 +1,87 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+#[test]
+fn stack_policy_stacks_past_max_windows() {
+    let variant = Variant::parse_dsl(
+        "* => dwindle() max_windows(count=3, overflow=stack)",
+        &monitor_rect(),
+    )
+    .unwrap();
+
+    let zones = variant.zones_for_windows(5);
+
+    assert_eq!(zones.len(), 5);
+
+    assert_eq!(zones[2], zones[3]);
+
+    assert_eq!(zones[2], zones[4]);
+
+    assert_eq!(variant.overflow_len(5), 0);
+}
+
+#[test]
+fn other_policies_report_overflow() {
+    for policy in [
+        OverflowPolicy::Float,
+        OverflowPolicy::Spill,
+        OverflowPolicy::MinimiseOldest,
+    ] {
+        let mut variant = Variant::new(1920, 1080);
+
+        variant.set_max_windows(Some(3));
+
+        variant.set_overflow_policy(policy.clone());
+
+        assert_eq!(variant.overflow_len(2), 0);
+
+        assert_eq!(variant.overflow_len(5), 2, "{:?}", policy);
+
+        assert!(variant.has_room_for(2));
+
+        assert!(!variant.has_room_for(3));
+
+        let zones = variant.zones_for_windows(3);
+
+        assert_ne!(zones[1], zones[2]);
+    }
+
+    assert_eq!(Variant::new(1920, 1080).overflow_len(40), 0);
+}
+
+#[test]
+fn max_windows_must_allow_a_window() {
+    let mut layout = Layout::new(1920, 1080);
+
+    assert_eq!(
+        LayoutEdit::SetMaxWindows {
+            variant_idx: 0,
+            max_windows: Some(0),
+        }
+        .apply(&mut layout)
+        .unwrap_err(),
+        LayoutError::NoMaxWindows
+    );
+
+    assert_eq!(
+        Variant::parse_dsl("* => max_windows(count=0)", &monitor_rect())
+            .unwrap_err()
+            .message,
+        "max windows must be at least 1"
+    );
+}
+
+#[test]
+fn overflow_settings_round_trip_through_the_printer() {
+    for src in [
+        "* => max_windows(count=4, overflow=spill)",
+        "* => max_windows(overflow=minimise_oldest)",
+    ] {
+        let variant = Variant::parse_dsl(src, &monitor_rect()).unwrap();
+
+        assert_eq!(variant.to_dsl().unwrap(), src);
+    }
+}
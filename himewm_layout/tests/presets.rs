@@ -0,0 +1,62 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+#[test]
+fn presets_are_valid_for_any_work_area() {
+    for work_area in [
+        zone(0, 0, 1920, 1080),
+        zone(0, 0, 3840, 2160),
+        zone(1920, 0, 3000, 1033),
+        zone(-1080, -500, 0, 1420),
+    ] {
+        for preset in Preset::ALL {
+            let layout = preset.layout(&work_area);
+
+            assert_eq!(layout.validate(), Ok(()), "{}", preset.name());
+
+            assert_eq!(layout.get_monitor_rect(), &work_area);
+
+            for variant in layout.get_variants() {
+                let zones = variant.zones_for_windows(6);
+
+                assert_eq!(zones.len(), 6, "{}", preset.name());
+
+                for zone in zones {
+                    assert!(zone.left >= work_area.left && zone.right <= work_area.right);
+
+                    assert!(zone.top >= work_area.top && zone.bottom <= work_area.bottom);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn presets_are_found_by_name() {
+    for preset in Preset::ALL {
+        assert_eq!(Preset::from_name(preset.name()), Some(preset));
+    }
+
+    assert_eq!(Preset::from_name("columns"), None);
+}
+
+#[test]
+fn tall_puts_the_master_on_the_left() {
+    let layout = Preset::Tall.layout(&zone(0, 0, 1920, 1080));
+
+    assert_eq!(
+        layout.get_variants()[0].render_ascii(3, 21, 7),
+        "\
++---------+---------+
+|         |    1    |
+|         |         |
+|    0    +---------+
+|         |    2    |
+|         |         |
++---------+---------+
+"
+    );
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+fn work_areas() -> Vec<Zone> {
+    vec![
+        zone(0, 0, 1920, 1080),
+        zone(0, 0, 2560, 1440),
+        zone(0, 0, 3840, 2160),
+        zone(1920, 40, 4480, 1440),
+    ]
+}
+
+fn thirds_layout() -> Layout {
+    Layout::parse_dsl(
+        "*; h[0.3 | *]; h[* | * | *]; h[* | v[* / * / *]] => spiral()\n* => meta(name=\"grid\") grid()",
+        &monitor_rect(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn relative_layouts_round_trip_through_json() {
+    let layout = thirds_layout();
+
+    let json = layout.to_relative().to_json().unwrap();
+
+    let relative = RelativeLayout::from_json(&json).unwrap();
+
+    assert_eq!(
+        serde_json::to_value(relative.to_layout(&monitor_rect())).unwrap(),
+        serde_json::to_value(&layout).unwrap()
+    );
+
+    assert_eq!(relative.to_json().unwrap(), json);
+}
+
+#[test]
+fn relative_layouts_tile_without_gaps() {
+    let relative = thirds_layout().to_relative();
+
+    for work_area in work_areas() {
+        let layout = relative.to_layout(&work_area);
+
+        assert_eq!(layout.validate(), Ok(()), "{:?}", work_area);
+
+        for variant in layout.get_variants() {
+            for n in 1..8 {
+                let zones = variant.zones_for_windows(n);
+
+                assert_eq!(
+                    zones
+                        .iter()
+                        .map(|zone| zone.w() as i64 * zone.h() as i64)
+                        .sum::<i64>(),
+                    work_area.w() as i64 * work_area.h() as i64,
+                    "{:?} {}",
+                    work_area,
+                    n
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn relative_from_zones_follow_the_work_area() {
+    let mut layout = Layout::new(1920, 1080);
+
+    let variant = &mut layout.get_variants_mut()[0];
+
+    variant.new_zone_vec(1920, 1080);
+
+    variant.split(1, 0, SplitDirection::Horizontal(640));
+
+    variant.set_end_tiling_behaviour(EndTilingBehaviour::Directional {
+        direction: Direction::Vertical,
+        start_from: 2,
+        from_zones: None,
+        zone_idx: 1,
+    });
+
+    layout.update_all(
+        0,
+        &EdgePadding::uniform(0),
+        &FrameInsets::default(),
+        false,
+        &monitor_rect(),
+    );
+
+    assert_eq!(layout.get_variants()[0].manual_zones_until(), 1);
+
+    let relative = layout.to_relative();
+
+    for work_area in work_areas() {
+        for n in 1..5 {
+            assert_eq!(
+                relative.to_layout(&work_area).get_variants()[0].zones_for_windows(n),
+                layout.scaled_to(&work_area).get_variants()[0].zones_for_windows(n),
+                "{:?} {}",
+                work_area,
+                n
+            );
+        }
+    }
+}
+
+#[test]
+fn absolute_variants_still_require_zones() {
+    let mut value = serde_json::to_value(Variant::new(1920, 1080)).unwrap();
+
+    value.as_object_mut().unwrap().remove("zones");
+
+    assert!(serde_json::from_value::<Variant>(value).is_err());
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+#[test]
+fn dwindle_extends_into_the_last_zone() {
+    let variant = Variant::parse_dsl("* => dwindle()", &monitor_rect()).unwrap();
+
+    assert_eq!(
+        variant.render_ascii(4, 25, 9),
+        "\
++-----------+-----------+
+|           |           |
+|           |     1     |
+|           |           |
+|     0     +-----+-----+
+|           |     |     |
+|           |  2  |  3  |
+|           |     |     |
++-----------+-----+-----+
+"
+    );
+}
+
+#[test]
+fn stacked_zones_share_a_label() {
+    let variant = Variant::parse_dsl("*; h[0.5 | *] => monocle(zone=1)", &monitor_rect()).unwrap();
+
+    assert_eq!(
+        variant.render_ascii(4, 21, 5),
+        "\
++---------+---------+
+|         |         |
+|    0    |  1,2,3  |
+|         |         |
++---------+---------+
+"
+    );
+}
+
+#[test]
+fn zones_for_windows_does_not_change_the_variant() {
+    let variant = Variant::parse_dsl("*; v[0.3 / *] => spiral()", &monitor_rect()).unwrap();
+
+    let zones = variant.zones_for_windows(5);
+
+    assert_eq!(zones.len(), 5);
+
+    assert_eq!(variant.get_zones().len(), 2);
+
+    assert_eq!(variant.zones_for_windows(0), Vec::new());
+}
+
+#[test]
+fn svg_draws_one_rect_per_zone() {
+    let variant = Variant::parse_dsl("*; h[0.25 | *]", &monitor_rect()).unwrap();
+
+    let svg = variant.render_svg(2);
+
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 1920 1080\""));
+
+    assert_eq!(svg.matches("<rect ").count(), 2);
+
+    assert!(svg.contains("<rect x=\"480\" y=\"0\" width=\"1440\" height=\"1080\""));
+
+    assert!(svg.contains(">1</text>"));
+
+    assert!(svg.ends_with("</svg>\n"));
+}
@@ -0,0 +1,121 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+fn variant(src: &str) -> Variant {
+    Variant::parse_dsl(src, &monitor_rect()).unwrap()
+}
+
+#[test]
+fn shared_edges_move_together() {
+    let mut variant = variant("*; h[0.5 | *]; h[0.5 | v[0.5 / *]]");
+
+    variant.resize_edge(2, 0, Edge::Right, 100).unwrap();
+
+    assert_eq!(
+        variant.get_zones()[2],
+        vec![
+            zone(0, 0, 1060, 1080),
+            zone(1060, 0, 1920, 540),
+            zone(1060, 540, 1920, 1080),
+        ]
+    );
+
+    variant.resize_edge(2, 2, Edge::Left, -200).unwrap();
+
+    assert_eq!(
+        variant.get_zones()[2],
+        vec![
+            zone(0, 0, 860, 1080),
+            zone(860, 0, 1920, 540),
+            zone(860, 540, 1920, 1080),
+        ]
+    );
+
+    assert_eq!(variant.get_split_tree(2), None);
+
+    assert!(variant.get_split_tree(1).is_some());
+
+    assert_eq!(variant.validate(&monitor_rect()), Ok(()));
+}
+
+#[test]
+fn edges_ending_in_a_t_junction_move_alone() {
+    let mut variant = variant("*; h[0.5 | *]; h[0.5 | v[0.5 / *]]");
+
+    variant.resize_edge(2, 1, Edge::Bottom, 60).unwrap();
+
+    assert_eq!(
+        variant.get_zones()[2],
+        vec![
+            zone(0, 0, 960, 1080),
+            zone(960, 0, 1920, 600),
+            zone(960, 600, 1920, 1080),
+        ]
+    );
+}
+
+#[test]
+fn crossing_edges_only_move_on_one_side() {
+    let mut variant = variant("*; h[0.5 | *]; h[0.5 | v[0.5 / *]]; h[0.5 v[0.5 / *] | v[0.5 / *]]");
+
+    variant.resize_edge(3, 0, Edge::Bottom, -140).unwrap();
+
+    assert_eq!(
+        variant.get_zones()[3],
+        vec![
+            zone(0, 0, 960, 400),
+            zone(0, 400, 960, 1080),
+            zone(960, 0, 1920, 540),
+            zone(960, 540, 1920, 1080),
+        ]
+    );
+}
+
+#[test]
+fn zones_may_not_shrink_below_their_minimum() {
+    let mut variant = variant("*; h[0.5 | *] => min_size(width=300, height=100)");
+
+    assert_eq!(
+        variant.resize_edge(1, 0, Edge::Right, 700),
+        Err(LayoutError::ZoneTooSmall { i: 1, j: 1 })
+    );
+
+    assert_eq!(
+        variant.resize_edge(1, 1, Edge::Left, -960),
+        Err(LayoutError::ZoneTooSmall { i: 1, j: 0 })
+    );
+
+    assert_eq!(
+        variant.get_zones()[1],
+        vec![zone(0, 0, 960, 1080), zone(960, 0, 1920, 1080)]
+    );
+
+    variant.resize_edge(1, 0, Edge::Right, 660).unwrap();
+
+    assert_eq!(variant.get_zones()[1][1], zone(1620, 0, 1920, 1080));
+}
+
+#[test]
+fn monitor_edges_cannot_be_moved() {
+    let mut variant = variant("*; h[0.5 | *]");
+
+    for (j, edge) in [
+        (0, Edge::Left),
+        (0, Edge::Top),
+        (1, Edge::Right),
+        (1, Edge::Bottom),
+    ] {
+        assert_eq!(
+            variant.resize_edge(1, j, edge, 10),
+            Err(LayoutError::EdgeOnMonitorBoundary { i: 1, j })
+        );
+    }
+
+    assert_eq!(
+        variant.resize_edge(3, 0, Edge::Right, 10),
+        Err(LayoutError::ZoneVecOutOfRange { i: 3, zones_len: 2 })
+    );
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+fn three_column_layout() -> Layout {
+    let mut layout = Layout::new(1920, 1080);
+
+    let variant = &mut layout.get_variants_mut()[0];
+
+    variant.new_zone_vec(1920, 1080);
+
+    variant.split(1, 0, SplitDirection::Horizontal(1281));
+
+    variant.new_zone_vec(1920, 1080);
+
+    variant.split(2, 0, SplitDirection::Horizontal(641));
+
+    variant.split(2, 0, SplitDirection::Horizontal(1281));
+
+    layout
+}
+
+fn shared_edges(zones: &[Zone]) -> Vec<(usize, usize)> {
+    let mut ret = Vec::new();
+
+    for (j, first) in zones.iter().enumerate() {
+        for (k, second) in zones.iter().enumerate() {
+            if first.right == second.left || first.bottom == second.top {
+                ret.push((j, k));
+            }
+        }
+    }
+
+    ret
+}
+
+#[test]
+fn scaled_layouts_stay_valid() {
+    let layout = three_column_layout();
+
+    assert_eq!(layout.validate(), Ok(()));
+
+    for work_area in [
+        zone(0, 0, 2560, 1440),
+        zone(0, 0, 3840, 2160),
+        zone(0, 0, 1366, 768),
+        zone(1920, 0, 3000, 1033),
+        zone(-1080, -500, 0, 1420),
+    ] {
+        let scaled = layout.scaled_to(&work_area);
+
+        assert_eq!(scaled.get_monitor_rect(), &work_area);
+
+        assert_eq!(scaled.validate(), Ok(()), "scaled to {:?}", work_area);
+    }
+}
+
+#[test]
+fn scaling_keeps_adjacent_zones_adjacent() {
+    let layout = three_column_layout();
+
+    let scaled = layout.scaled_to(&zone(0, 0, 2561, 1441));
+
+    for (original, scaled) in layout.get_variants()[0]
+        .get_zones()
+        .iter()
+        .zip(scaled.get_variants()[0].get_zones().iter())
+    {
+        assert_eq!(shared_edges(original), shared_edges(scaled));
+    }
+}
+
+#[test]
+fn scaling_to_same_size_only_translates() {
+    let layout = three_column_layout();
+
+    let scaled = layout.scaled_to(&zone(100, 50, 2020, 1130));
+
+    for (original, scaled) in layout.get_variants()[0]
+        .get_zones()
+        .iter()
+        .flatten()
+        .zip(scaled.get_variants()[0].get_zones().iter().flatten())
+    {
+        assert_eq!(scaled.left, original.left + 100);
+
+        assert_eq!(scaled.top, original.top + 50);
+
+        assert_eq!(scaled.w(), original.w());
+
+        assert_eq!(scaled.h(), original.h());
+    }
+}
+
+#[test]
+fn scaling_round_trips_through_larger_monitors() {
+    let layout = three_column_layout();
+
+    let round_trip = layout
+        .scaled_to(&zone(0, 0, 3840, 2160))
+        .scaled_to(layout.get_monitor_rect());
+
+    assert_eq!(
+        round_trip.get_variants()[0].get_zones(),
+        layout.get_variants()[0].get_zones()
+    );
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+fn extended_variant(n: usize, smart_gaps: bool, frame_insets: &FrameInsets) -> Variant {
+    let mut variant = Variant::new(1920, 1080);
+
+    variant.update(
+        8,
+        &EdgePadding::uniform(16),
+        frame_insets,
+        smart_gaps,
+        &monitor_rect(),
+    );
+
+    while variant.positions_len() < n {
+        variant.extend();
+
+        variant.update(
+            8,
+            &EdgePadding::uniform(16),
+            frame_insets,
+            smart_gaps,
+            &monitor_rect(),
+        );
+    }
+
+    variant
+}
+
+#[test]
+fn lone_window_fills_work_area_with_smart_gaps() {
+    let variant = extended_variant(1, true, &no_frame_insets());
+
+    assert_eq!(
+        variant.get_positions_at(0)[0],
+        Position {
+            x: 0,
+            y: 0,
+            cx: 1920,
+            cy: 1080,
+        }
+    );
+}
+
+#[test]
+fn lone_window_keeps_frame_insets_with_smart_gaps() {
+    let variant = extended_variant(1, true, &FrameInsets::default());
+
+    assert_eq!(
+        variant.get_positions_at(0)[0],
+        Position {
+            x: -7,
+            y: 0,
+            cx: 1934,
+            cy: 1087,
+        }
+    );
+}
+
+#[test]
+fn lone_window_is_padded_without_smart_gaps() {
+    let variant = extended_variant(1, false, &no_frame_insets());
+
+    assert_eq!(
+        variant.get_positions_at(0)[0],
+        Position {
+            x: 16,
+            y: 16,
+            cx: 1888,
+            cy: 1048,
+        }
+    );
+}
+
+#[test]
+fn multiple_windows_stay_padded_with_smart_gaps() {
+    let variant = extended_variant(2, true, &no_frame_insets());
+
+    assert_eq!(
+        variant.get_positions_at(1),
+        &vec![
+            Position {
+                x: 16,
+                y: 16,
+                cx: 1888,
+                cy: 516,
+            },
+            Position {
+                x: 16,
+                y: 548,
+                cx: 1888,
+                cy: 516,
+            },
+        ]
+    );
+}
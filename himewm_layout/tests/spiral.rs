@@ -0,0 +1,87 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+#[test]
+fn spiral_turns_clockwise_by_default() {
+    assert_eq!(
+        zones("* => spiral()", 5),
+        vec![
+            zone(0, 0, 960, 1080),
+            zone(960, 0, 1920, 540),
+            zone(1440, 540, 1920, 1080),
+            zone(960, 810, 1440, 1080),
+            zone(960, 540, 1440, 810),
+        ]
+    );
+}
+
+#[test]
+fn spiral_can_turn_counter_clockwise() {
+    assert_eq!(
+        zones("* => spiral(clockwise=false)", 5),
+        vec![
+            zone(0, 0, 960, 1080),
+            zone(960, 540, 1920, 1080),
+            zone(1440, 0, 1920, 540),
+            zone(960, 0, 1440, 270),
+            zone(960, 270, 1440, 540),
+        ]
+    );
+}
+
+#[test]
+fn spiral_honours_ratio_and_start_direction() {
+    assert_eq!(
+        zones("* => spiral(ratio=0.6, start=v)", 4),
+        vec![
+            zone(0, 0, 1920, 648),
+            zone(768, 648, 1920, 1080),
+            zone(0, 821, 768, 1080),
+            zone(0, 648, 768, 821),
+        ]
+    );
+}
+
+#[test]
+fn dwindle_always_splits_the_last_zone_towards_the_corner() {
+    assert_eq!(
+        zones("* => dwindle()", 5),
+        vec![
+            zone(0, 0, 960, 1080),
+            zone(960, 0, 1920, 540),
+            zone(960, 540, 1440, 1080),
+            zone(1440, 540, 1920, 810),
+            zone(1440, 810, 1920, 1080),
+        ]
+    );
+
+    assert_eq!(
+        zones("* => dwindle(ratio=0.6, start=v)", 4),
+        vec![
+            zone(0, 0, 1920, 648),
+            zone(0, 648, 1152, 1080),
+            zone(1152, 648, 1920, 907),
+            zone(1152, 907, 1920, 1080),
+        ]
+    );
+}
+
+#[test]
+fn spiral_and_dwindle_tile_without_gaps() {
+    for src in [
+        "* => spiral()",
+        "* => spiral(clockwise=false, ratio=0.3)",
+        "* => dwindle(ratio=0.7)",
+    ] {
+        let mut variant = Variant::parse_dsl(src, &monitor_rect()).unwrap();
+
+        while variant.get_zones().len() < 9 {
+            variant.extend();
+        }
+
+        assert_eq!(variant.validate(&monitor_rect()), Ok(()), "{}", src);
+    }
+}
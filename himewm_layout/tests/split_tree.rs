@@ -0,0 +1,210 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+fn trees() -> Vec<SplitTree> {
+    vec![
+        SplitTree::Leaf,
+        SplitTree::split(
+            Direction::Horizontal,
+            0.25,
+            SplitTree::Leaf,
+            SplitTree::Leaf,
+        ),
+        SplitTree::split(
+            Direction::Horizontal,
+            0.5,
+            SplitTree::Leaf,
+            SplitTree::split(Direction::Vertical, 0.5, SplitTree::Leaf, SplitTree::Leaf),
+        ),
+    ]
+}
+
+fn tree_variant() -> Variant {
+    Variant::from_split_trees(&monitor_rect(), trees()).unwrap()
+}
+
+#[test]
+fn from_split_trees_builds_matching_zones() {
+    let variant = tree_variant();
+
+    assert_eq!(
+        variant.get_zones(),
+        &vec![
+            vec![zone(0, 0, 1920, 1080)],
+            vec![zone(0, 0, 480, 1080), zone(480, 0, 1920, 1080)],
+            vec![
+                zone(0, 0, 960, 1080),
+                zone(960, 0, 1920, 540),
+                zone(960, 540, 1920, 1080),
+            ],
+        ]
+    );
+
+    assert_eq!(variant.manual_zones_until(), 3);
+
+    assert_eq!(variant.validate(&monitor_rect()), Ok(()));
+
+    assert_eq!(
+        Variant::from_split_trees(&monitor_rect(), vec![trees().remove(1)]).unwrap_err(),
+        LayoutError::WrongZoneCount {
+            i: 0,
+            expected: 1,
+            found: 2,
+        }
+    );
+
+    assert_eq!(
+        Variant::from_split_trees(&monitor_rect(), Vec::new()).unwrap_err(),
+        LayoutError::NoZones
+    );
+}
+
+#[test]
+fn split_ratio_edits_reflow_sibling_zones() {
+    let mut variant = tree_variant();
+
+    variant.try_set_split_ratio(2, 0, 0.25).unwrap();
+
+    assert_eq!(
+        variant.get_zones()[2],
+        vec![
+            zone(0, 0, 480, 1080),
+            zone(480, 0, 1920, 540),
+            zone(480, 540, 1920, 1080),
+        ]
+    );
+
+    variant.try_set_split_ratio(2, 2, 0.25).unwrap();
+
+    assert_eq!(
+        variant.get_zones()[2],
+        vec![
+            zone(0, 0, 480, 1080),
+            zone(480, 0, 1920, 270),
+            zone(480, 270, 1920, 1080),
+        ]
+    );
+
+    assert_eq!(variant.get_zones()[1], tree_variant().get_zones()[1]);
+}
+
+#[test]
+fn invalid_split_ratio_edits_are_rejected() {
+    let mut variant = tree_variant();
+
+    assert_eq!(
+        variant.try_set_split_ratio(2, 1, 0.5),
+        Err(LayoutError::InSplitTree {
+            i: 2,
+            error: Box::new(LayoutError::SplitTreeNodeOutOfRange { node: 1 }),
+        })
+    );
+
+    assert_eq!(
+        variant.try_set_split_ratio(1, 0, 1.0),
+        Err(LayoutError::InSplitTree {
+            i: 1,
+            error: Box::new(LayoutError::InvalidTreeRatio {
+                node: 0,
+                ratio: 1.0,
+            }),
+        })
+    );
+
+    assert_eq!(
+        variant.try_set_split_ratio(3, 0, 0.5),
+        Err(LayoutError::NoSplitTree { i: 3 })
+    );
+
+    assert_eq!(variant.get_zones(), tree_variant().get_zones());
+}
+
+#[test]
+fn zone_edits_detach_only_the_edited_tree() {
+    let mut variant = tree_variant();
+
+    variant.split(1, 1, SplitDirection::Vertical(540));
+
+    assert_eq!(variant.get_split_tree(1), None);
+
+    assert_eq!(variant.get_split_tree(0), Some(&trees()[0]));
+
+    assert_eq!(variant.get_split_tree(2), Some(&trees()[2]));
+
+    assert_eq!(
+        variant.try_set_split_ratio(1, 0, 0.5),
+        Err(LayoutError::NoSplitTree { i: 1 })
+    );
+
+    variant.try_set_split_ratio(2, 0, 0.25).unwrap();
+
+    assert_eq!(variant.get_zones()[2][0], zone(0, 0, 480, 1080));
+}
+
+#[test]
+fn zone_vector_edits_reindex_the_trees() {
+    let mut variant = tree_variant();
+
+    variant.swap_zone_vectors(1, 2);
+
+    assert_eq!(variant.get_split_tree(1), Some(&trees()[2]));
+
+    assert_eq!(variant.get_split_tree(2), Some(&trees()[1]));
+
+    variant.clone_zone_vec(1);
+
+    assert_eq!(variant.get_split_tree(3), Some(&trees()[2]));
+
+    variant.delete_zones(1);
+
+    assert_eq!(variant.get_split_tree(1), Some(&trees()[1]));
+
+    assert_eq!(variant.get_split_tree(2), Some(&trees()[2]));
+
+    variant.new_zone_vec(1920, 1080);
+
+    assert_eq!(variant.get_split_tree(3), Some(&SplitTree::Leaf));
+
+    assert_eq!(variant.get_split_trees().unwrap().len(), 4);
+}
+
+#[test]
+fn split_trees_round_trip_through_json() {
+    let variant = tree_variant();
+
+    let value = serde_json::to_value(&variant).unwrap();
+
+    let reparsed: Variant = serde_json::from_value(value.clone()).unwrap();
+
+    assert_eq!(reparsed.get_split_trees(), variant.get_split_trees());
+
+    assert_eq!(reparsed.get_zones(), variant.get_zones());
+
+    let mut detached = tree_variant();
+
+    detached.split(1, 1, SplitDirection::Vertical(540));
+
+    let reparsed: Variant =
+        serde_json::from_value(serde_json::to_value(&detached).unwrap()).unwrap();
+
+    assert_eq!(reparsed.get_split_trees(), detached.get_split_trees());
+
+    let mut old_format = value;
+
+    old_format
+        .as_object_mut()
+        .unwrap()
+        .remove("split_trees")
+        .unwrap();
+
+    let reparsed: Variant = serde_json::from_value(old_format).unwrap();
+
+    assert_eq!(reparsed.get_split_trees(), None);
+
+    assert_eq!(reparsed.get_zones(), variant.get_zones());
+
+    assert_eq!(reparsed.validate(&monitor_rect()), Ok(()));
+}
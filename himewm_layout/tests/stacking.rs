@@ -0,0 +1,48 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn max_zones_stacks_windows_onto_the_last_zone() {
+    let src = "* => directional(direction=h) max_zones(count=3)";
+
+    assert_eq!(
+        zones(src, 3),
+        vec![
+            zone(0, 0, 640, 1080),
+            zone(640, 0, 1280, 1080),
+            zone(1280, 0, 1920, 1080),
+        ]
+    );
+
+    assert_eq!(
+        zones(src, 5),
+        vec![
+            zone(0, 0, 640, 1080),
+            zone(640, 0, 1280, 1080),
+            zone(1280, 0, 1920, 1080),
+            zone(1280, 0, 1920, 1080),
+            zone(1280, 0, 1920, 1080),
+        ]
+    );
+}
+
+#[test]
+fn monocle_gives_every_window_the_full_rect() {
+    for n in 1..5 {
+        assert_eq!(zones("* => monocle()", n), vec![monitor_rect(); n]);
+    }
+}
+
+#[test]
+fn monocle_stacks_onto_the_end_zone() {
+    assert_eq!(
+        zones("*; h[* | *] => monocle(zone=0)", 4),
+        vec![
+            zone(0, 0, 960, 1080),
+            zone(960, 0, 1920, 1080),
+            zone(0, 0, 960, 1080),
+            zone(0, 0, 960, 1080),
+        ]
+    );
+}
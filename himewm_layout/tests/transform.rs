@@ -0,0 +1,258 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+const BEHAVIOURS: [&str; 13] = [
+    "*",
+    "* => directional(direction=h)",
+    "*; h[0.25 | *] => directional(direction=v, zone=1)",
+    "*; h[0.25 | *]; h[0.25 | v[0.5 / *]] => directional(direction=h, start_from=2, zone=0)",
+    "* => spiral(ratio=0.4, clockwise=true, start=h)",
+    "* => spiral(clockwise=false, start=v)",
+    "* => dwindle(ratio=0.6, start=h)",
+    "* => grid(prefer=rows, fill=expand_last)",
+    "* => grid(prefer=columns, fill=stretch)",
+    "* => master_stack(count=2, ratio=0.6, stack=v, side=left)",
+    "*; v[0.3 / *] => centered_master(ratio=0.5, zone=1)",
+    "*; h[0.5 | *] => monocle(zone=1)",
+    "* => repeating(zone=0) repeat_split(step=0, direction=h, ratio=0.5, offset=0, swap=false) repeat_split(step=1, direction=h, ratio=0.5, offset=0, swap=false) repeat_split(step=1, direction=v, ratio=0.4, offset=0, swap=true)",
+];
+
+fn assert_matches_transformed_zones(
+    transformed: &Layout,
+    layout: &Layout,
+    map_zone: impl Fn(&Zone) -> Zone,
+    src: &str,
+) {
+    assert_eq!(transformed.validate(), Ok(()), "{}", src);
+
+    for n in 1..9 {
+        assert_eq!(
+            transformed.get_variants()[0].zones_for_windows(n),
+            layout.get_variants()[0]
+                .zones_for_windows(n)
+                .iter()
+                .map(&map_zone)
+                .collect::<Vec<Zone>>(),
+            "{} {}",
+            src,
+            n
+        );
+    }
+}
+
+fn rotated_zone(zone: &Zone, rect: &Zone, quarter_turns: i32) -> Zone {
+    let mut ret = zone.clone();
+
+    let mut rect = rect.clone();
+
+    for _ in 0..quarter_turns {
+        ret = ret.rotated(&rect);
+
+        rect = rect.rotated(&rect);
+    }
+
+    ret
+}
+
+#[test]
+fn mirrored_layouts_mirror_the_generated_zones() {
+    for src in BEHAVIOURS {
+        let layout = Layout::parse_dsl(src, &monitor_rect()).unwrap();
+
+        for axis in [Axis::Horizontal, Axis::Vertical] {
+            assert_matches_transformed_zones(
+                &layout.mirrored(axis),
+                &layout,
+                |zone| zone.mirrored(axis, &monitor_rect()),
+                src,
+            );
+        }
+    }
+}
+
+#[test]
+fn rotated_layouts_rotate_the_generated_zones() {
+    for src in BEHAVIOURS {
+        let layout = Layout::parse_dsl(src, &monitor_rect()).unwrap();
+
+        for quarter_turns in 1..4 {
+            assert_matches_transformed_zones(
+                &layout.rotated(quarter_turns),
+                &layout,
+                |zone| rotated_zone(zone, &monitor_rect(), quarter_turns),
+                src,
+            );
+        }
+    }
+}
+
+#[test]
+fn combined_transforms_compose() {
+    for src in BEHAVIOURS {
+        let layout = Layout::parse_dsl(src, &monitor_rect()).unwrap();
+
+        assert_matches_transformed_zones(
+            &layout.rotated(1).mirrored(Axis::Vertical).rotated(2),
+            &layout,
+            |zone| {
+                let rect = monitor_rect().rotated(&monitor_rect());
+
+                rotated_zone(
+                    &rotated_zone(zone, &monitor_rect(), 1).mirrored(Axis::Vertical, &rect),
+                    &rect,
+                    2,
+                )
+            },
+            src,
+        );
+
+        assert_eq!(
+            serde_json::to_value(layout.mirrored(Axis::Horizontal).mirrored(Axis::Horizontal))
+                .unwrap(),
+            serde_json::to_value(&layout).unwrap(),
+            "{}",
+            src
+        );
+
+        assert_eq!(
+            serde_json::to_value(layout.rotated(4)).unwrap(),
+            serde_json::to_value(&layout).unwrap(),
+            "{}",
+            src
+        );
+    }
+}
+
+#[test]
+fn mirrored_master_stack_puts_the_master_on_the_other_side() {
+    let mirrored = Preset::Tall
+        .layout(&monitor_rect())
+        .mirrored(Axis::Horizontal);
+
+    let expected =
+        Layout::parse_dsl("* => master_stack(stack=v, side=right)", &monitor_rect()).unwrap();
+
+    assert_eq!(
+        mirrored.get_variants()[0].zones_for_windows(3),
+        expected.get_variants()[0].zones_for_windows(3)
+    );
+
+    assert_eq!(mirrored.validate(), Ok(()));
+}
+
+#[test]
+fn mirrored_zones_keep_their_order_and_split_trees() {
+    let layout = Layout::parse_dsl("* ; h[0.25 | *]", &monitor_rect()).unwrap();
+
+    let mut mirrored = layout.mirrored(Axis::Horizontal);
+
+    let variant = &mut mirrored.get_variants_mut()[0];
+
+    assert_eq!(
+        variant.get_zones()[1],
+        vec![zone(1440, 0, 1920, 1080), zone(0, 0, 1440, 1080)]
+    );
+
+    assert_eq!(
+        variant.get_split_trees(),
+        layout.get_variants()[0].get_split_trees()
+    );
+
+    variant.try_set_split_ratio(1, 0, 0.5).unwrap();
+
+    assert_eq!(
+        variant.get_zones()[1],
+        vec![zone(960, 0, 1920, 1080), zone(0, 0, 960, 1080)]
+    );
+}
+
+#[test]
+fn mirrored_repeating_splits_match_mirrored_zones() {
+    let (layout, _) = Layout::from_json(&golden("v1_repeating")).unwrap();
+
+    let monitor_rect = layout.get_monitor_rect().clone();
+
+    for axis in [Axis::Horizontal, Axis::Vertical] {
+        assert_matches_transformed_zones(
+            &layout.mirrored(axis),
+            &layout,
+            |zone| zone.mirrored(axis, &monitor_rect),
+            "v1_repeating",
+        );
+    }
+}
+
+#[test]
+fn rotated_layouts_swap_orientation() {
+    let layout = Layout::parse_dsl(
+        "* ; h[0.25 | *] => min_size(width=300, height=100)",
+        &monitor_rect(),
+    )
+    .unwrap();
+
+    let rotated = layout.rotated(1);
+
+    assert_eq!(rotated.get_monitor_rect(), &zone(0, 0, 1080, 1920));
+
+    let variant = &rotated.get_variants()[0];
+
+    assert_eq!(
+        variant.get_zones()[1],
+        vec![zone(0, 0, 1080, 480), zone(0, 480, 1080, 1920)]
+    );
+
+    let dsl = variant.to_dsl().unwrap();
+
+    assert_eq!(
+        dsl,
+        "*; h[0.25 | *] => min_size(width=300, height=100, overflow=stack) transform(mirrored=false, turns=1)"
+    );
+
+    let reparsed = Variant::parse_dsl(&dsl, rotated.get_monitor_rect()).unwrap();
+
+    assert_eq!(reparsed.get_zones(), variant.get_zones());
+
+    assert_eq!(reparsed.zones_for_windows(5), variant.zones_for_windows(5));
+
+    assert_eq!(
+        layout.rotated(-1).get_monitor_rect(),
+        rotated.get_monitor_rect()
+    );
+
+    assert_eq!(
+        layout.rotated(2).get_variants()[0].get_zones()[1],
+        layout.mirrored(Axis::Horizontal).get_variants()[0].get_zones()[1]
+    );
+}
+
+#[test]
+fn rotated_min_sizes_are_checked_against_the_rotated_zones() {
+    let layout = Layout::parse_dsl(
+        "* => directional(direction=h) min_size(width=700) zone_min_size(zone=2, width=700, height=600)",
+        &monitor_rect(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        layout.get_variants()[0].zones_for_windows(3),
+        vec![
+            zone(0, 0, 960, 1080),
+            zone(960, 0, 1920, 1080),
+            zone(960, 0, 1920, 1080),
+        ]
+    );
+
+    let rotated = layout.rotated(1);
+
+    assert_eq!(
+        rotated.get_variants()[0].zones_for_windows(3),
+        vec![
+            zone(0, 0, 1080, 640),
+            zone(0, 640, 1080, 1280),
+            zone(0, 1280, 1080, 1920),
+        ]
+    );
+}
@@ -0,0 +1,164 @@
+mod common;
+
+use common::*;
+
+use himewm_layout::*;
+
+#[test]
+fn generated_stacks_are_valid() {
+    let mut variant = Variant::parse_dsl("* => monocle()", &monitor_rect()).unwrap();
+
+    variant.extend();
+
+    variant.extend();
+
+    assert_eq!(variant.get_zones().len(), 3);
+
+    assert_eq!(variant.validate(&monitor_rect()), Ok(()));
+}
+
+#[test]
+fn manual_zones_may_not_overlap() {
+    let mut variant = Variant::new(1920, 1080);
+
+    variant.new_zone_vec(1920, 1080);
+
+    variant.get_zones_mut()[1].push(monitor_rect());
+
+    assert_eq!(
+        variant.validate(&monitor_rect()),
+        Err(vec![LayoutError::OverlappingZones { i: 1, j: 0, k: 1 }])
+    );
+}
+
+#[test]
+fn generated_zones_are_still_checked() {
+    let mut variant = Variant::parse_dsl("* => monocle()", &monitor_rect()).unwrap();
+
+    variant.extend();
+
+    variant.get_zones_mut()[1][1] = zone(0, 0, 960, 1080);
+
+    assert_eq!(
+        variant.validate(&monitor_rect()),
+        Err(vec![LayoutError::OverlappingZones { i: 1, j: 0, k: 1 }])
+    );
+}
+
+#[test]
+fn gaps_are_rejected() {
+    let mut variant = Variant::new(1920, 1080);
+
+    variant.get_zones_mut()[0][0] = zone(0, 0, 960, 1080);
+
+    assert_eq!(
+        variant.validate(&monitor_rect()),
+        Err(vec![LayoutError::Gap { i: 0 }])
+    );
+}
+
+#[test]
+fn zones_outside_the_monitor_are_rejected() {
+    let mut variant = Variant::new(1920, 1080);
+
+    variant.get_zones_mut()[0][0] = zone(0, 0, 1920, 1200);
+
+    assert_eq!(
+        variant.validate(&monitor_rect()),
+        Err(vec![LayoutError::ZoneOutsideMonitor { i: 0, j: 0 }])
+    );
+}
+
+#[test]
+fn empty_zones_are_rejected() {
+    let mut variant = Variant::new(1920, 1080);
+
+    variant.new_zone_vec(1920, 1080);
+
+    variant.get_zones_mut()[1][0] = zone(0, 0, 960, 1080);
+
+    variant.get_zones_mut()[1].push(zone(960, 0, 960, 1080));
+
+    assert_eq!(
+        variant.validate(&monitor_rect()),
+        Err(vec![LayoutError::EmptyZone { i: 1, j: 1 }])
+    );
+}
+
+#[test]
+fn manual_zones_until_must_be_in_range() {
+    let mut value = serde_json::to_value(Variant::new(1920, 1080)).unwrap();
+
+    value["manual_zones_until"] = serde_json::json!(2);
+
+    let variant: Variant = serde_json::from_value(value).unwrap();
+
+    assert_eq!(
+        variant.validate(&monitor_rect()),
+        Err(vec![LayoutError::ManualZonesUntilOutOfRange {
+            manual_zones_until: 2,
+            zones_len: 1,
+        }])
+    );
+}
+
+#[test]
+fn default_variant_idx_must_be_in_range() {
+    let mut layout = Layout::new(1920, 1080);
+
+    layout.set_default_variant_idx(1);
+
+    assert_eq!(
+        layout.validate(),
+        Err(vec![LayoutError::DefaultVariantOutOfRange {
+            default_variant_idx: 1,
+            variants_len: 1,
+        }])
+    );
+}
+
+#[test]
+fn repeating_splits_may_not_be_empty() {
+    let mut variant = Variant::new(1920, 1080);
+
+    variant.set_end_tiling_behaviour(EndTilingBehaviour::Repeating {
+        splits: vec![Vec::new()],
+        zone_idx: 0,
+    });
+
+    assert_eq!(
+        variant.validate(&monitor_rect()),
+        Err(vec![LayoutError::EmptyRepeatingSplits { i: Some(0) }])
+    );
+
+    variant.set_end_tiling_behaviour(EndTilingBehaviour::default_repeating());
+
+    assert_eq!(
+        variant.validate(&monitor_rect()),
+        Err(vec![LayoutError::EmptyRepeatingSplits { i: None }])
+    );
+}
+
+#[test]
+fn repeating_split_offsets_must_index_the_split_zones() {
+    let mut variant = Variant::new(1920, 1080);
+
+    variant.set_end_tiling_behaviour(EndTilingBehaviour::Repeating {
+        splits: vec![vec![RepeatingSplit::new(
+            Direction::Horizontal,
+            0.5,
+            99,
+            false,
+        )]],
+        zone_idx: 0,
+    });
+
+    assert_eq!(
+        variant.validate(&monitor_rect()),
+        Err(vec![LayoutError::SplitIdxOffsetOutOfRange {
+            i: 0,
+            j: 0,
+            split_idx_offset: 99,
+        }])
+    );
+}